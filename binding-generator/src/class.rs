@@ -65,6 +65,11 @@ impl<'tu, 'ge> Class<'tu, 'ge> {
 			&& !self.has_bases()
 			&& !self
 				.for_each_field(|field| {
+					// bitfields can't be represented in a `#[repr(C)]` struct, so such classes are boxed and the fields are
+					// only reachable through the generated accessors
+					if field.is_bit_field() {
+						return WalkAction::Interrupt;
+					}
 					let type_ref = field.type_ref();
 					WalkAction::continue_until(!type_ref.kind().is_copy(type_ref.type_hint()))
 				})
@@ -128,6 +133,53 @@ impl<'tu, 'ge> Class<'tu, 'ge> {
 		}
 	}
 
+	/// Returns true if the current class is a C++ `std::function` specialization
+	///
+	/// Those are only bound for the functions listed in `FUNC_CLOSURE_ARG`: there is no way to tell from the declaration whether
+	/// the callee calls the function immediately (possibly from several threads) or stores it for later, so the lifetime of the
+	/// Rust closure can't be decided in general.
+	pub fn is_std_function(&self) -> bool {
+		let cpp_refname = self.cpp_name(CppNameStyle::Reference);
+		cpp_refname.starts_with("std::") && cpp_refname.contains("::function")
+	}
+
+	/// For a `std::function` specialization returns its argument types if it can be passed from Rust as a closure
+	///
+	/// That's the case when it returns `void` and all of its arguments can be passed to Rust by value: the copyable types and the
+	/// boxed classes (those are copied to the heap), const references to those are dereferenced.
+	pub fn std_function_closure_args(&self) -> Option<Vec<TypeRef<'tu, 'ge>>> {
+		match self {
+			&Self::Clang { entity, gen_env, .. } if self.is_std_function() => {
+				let signature = entity.get_type()?.get_template_argument_types()?.into_iter().next()??;
+				if !TypeRef::new(signature.get_result_type()?, gen_env).kind().is_void() {
+					return None;
+				}
+				signature
+					.get_argument_types()?
+					.into_iter()
+					.map(|arg| {
+						let arg = TypeRef::new(arg, gen_env);
+						let arg = match arg.kind().as_reference() {
+							Some(pointee) if pointee.constness().is_const() => pointee.into_owned(),
+							Some(_) => return None,
+							None => arg.clone(),
+						};
+						let is_by_value = {
+							let kind = arg.kind();
+							kind.as_string(arg.type_hint()).is_none()
+								&& (kind.is_copy(arg.type_hint())
+									|| kind
+										.as_class()
+										.map_or(false, |cls| cls.kind().is_boxed() && !cls.is_abstract()))
+						};
+						is_by_value.then_some(arg)
+					})
+					.collect()
+			}
+			Self::Clang { .. } | Self::Desc(_) => None,
+		}
+	}
+
 	pub fn template_kind(&self) -> TemplateKind<'tu, 'ge> {
 		match self {
 			&Self::Clang { entity, gen_env, .. } => {
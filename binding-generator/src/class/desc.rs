@@ -54,6 +54,11 @@ impl<'tu, 'ge> ClassDesc<'tu, 'ge> {
 		Class::new_desc(Self::simple("cv::Size", "core"))
 	}
 
+	/// `cv::TermCriteria`
+	pub fn cv_term_criteria() -> Class<'tu, 'ge> {
+		Class::new_desc(Self::simple("cv::TermCriteria", "core"))
+	}
+
 	/// `cv::Point`
 	pub fn cv_point() -> Class<'tu, 'ge> {
 		Class::new_desc(Self::simple("cv::Point", "core"))
@@ -114,6 +119,11 @@ impl<'tu, 'ge> ClassDesc<'tu, 'ge> {
 		Class::new_desc(Self::system("cv::String", "core"))
 	}
 
+	/// `std::string`
+	pub fn std_string() -> Class<'tu, 'ge> {
+		Class::new_desc(Self::system("std::string", "core"))
+	}
+
 	/// `cv::MatConstIterator`
 	pub fn cv_matconstiterator() -> Class<'tu, 'ge> {
 		Class::new_desc(Self::boxed("cv::MatConstIterator", "core"))
@@ -143,6 +153,11 @@ impl<'tu, 'ge> ClassDesc<'tu, 'ge> {
 		Class::new_desc(Self::boxed("cv::_InputOutputArray", "core"))
 	}
 
+	/// `cv::Algorithm`
+	pub fn cv_algorithm() -> Class<'tu, 'ge> {
+		Class::new_desc(Self::boxed("cv::Algorithm", "core"))
+	}
+
 	/// `cv::Feature2D`
 	pub fn cv_feature2d() -> Class<'tu, 'ge> {
 		Class::new_desc(Self::boxed("cv::Feature2D", "features2d"))
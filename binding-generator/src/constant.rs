@@ -8,8 +8,9 @@ use clang::{Entity, EntityKind, EvaluationResult};
 use crate::comment::strip_doxygen_comment_markers;
 use crate::debug::LocationName;
 use crate::element::ExcludeKind;
+use crate::enumeration::rust_enum_constant_value;
 use crate::type_ref::CppNameStyle;
-use crate::{settings, DefaultElement, Element, EntityElement, NameDebug};
+use crate::{settings, DefaultElement, Element, EntityElement, Enum, NameDebug};
 
 pub fn render_constant_rust(tokens: &[Token]) -> Option<Value> {
 	let mut out = Value {
@@ -118,15 +119,17 @@ impl<'tu> Const<'tu> {
 					render_constant_rust(&tokens[1..])
 				}
 			}
-			EntityKind::EnumConstantDecl => Some(Value {
-				kind: ValueKind::Integer,
-				value: self
+			EntityKind::EnumConstantDecl => {
+				let (signed_value, unsigned_value) = self.entity.get_enum_constant_value().expect("Can't get enum constant value");
+				let repr = self
 					.entity
-					.get_enum_constant_value()
-					.expect("Can't get enum constant value")
-					.0
-					.to_string(),
-			}),
+					.get_semantic_parent()
+					.map_or("i32", |enm| Enum::new(enm).rust_repr());
+				Some(Value {
+					kind: ValueKind::Integer,
+					value: rust_enum_constant_value(repr, signed_value, unsigned_value),
+				})
+			}
 			EntityKind::VarDecl => self.entity.evaluate().map(render_evaluation_result_rust),
 			_ => {
 				unreachable!("Invalid entity type for constant")
@@ -49,6 +49,19 @@ impl<'tu> Enum<'tu> {
 		}
 	}
 
+	/// Rust integer type used to store the enum values, it's `i32` unless the enum is explicitly declared with a 64-bit
+	/// underlying type, e.g. `enum Foo : int64_t`
+	pub fn rust_repr(&self) -> &'static str {
+		let underlying_type = self
+			.as_typedefed()
+			.unwrap_or(self.entity)
+			.get_enum_underlying_type()
+			.map(|t| t.get_canonical_type());
+		underlying_type.map_or("i32", |typ| {
+			rust_repr_for_underlying(typ.get_sizeof().unwrap_or_default(), typ.is_unsigned_integer())
+		})
+	}
+
 	pub fn consts(&self) -> Vec<Const> {
 		let mut out = vec![];
 		self.as_typedefed().unwrap_or(self.entity).visit_children(|const_decl, _| {
@@ -116,3 +129,62 @@ impl fmt::Debug for Enum<'_> {
 			.finish()
 	}
 }
+
+fn rust_repr_for_underlying(size: usize, is_unsigned: bool) -> &'static str {
+	match (size > 4, is_unsigned) {
+		(true, true) => "u64",
+		(true, false) => "i64",
+		(false, _) => "i32",
+	}
+}
+
+/// Value of the enum constant as rendered for the enum with the Rust integer type `repr`
+///
+/// Clang reports the non-negative unscoped enums as `unsigned int`, but those are still emitted as `i32` so the values
+/// that don't fit must wrap around, e.g. `0xFFFF0000` is rendered as `-65536`. Only `u64` enums use the unsigned value.
+pub(crate) fn rust_enum_constant_value(repr: &str, signed_value: i64, unsigned_value: u64) -> String {
+	if repr == "u64" {
+		unsigned_value.to_string()
+	} else {
+		signed_value.to_string()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{rust_enum_constant_value, rust_repr_for_underlying};
+
+	#[test]
+	fn underlying_type_repr() {
+		assert_eq!("i32", rust_repr_for_underlying(4, false));
+		assert_eq!("i32", rust_repr_for_underlying(4, true));
+		assert_eq!("i32", rust_repr_for_underlying(1, true));
+		assert_eq!("i64", rust_repr_for_underlying(8, false));
+		assert_eq!("u64", rust_repr_for_underlying(8, true));
+	}
+
+	#[test]
+	fn enum_constant_value() {
+		// `unsigned int` enum, e.g. `Mat::MAGIC_MASK = 0xFFFF0000`, clang sign-extends the 32-bit value
+		let repr = rust_repr_for_underlying(4, true);
+		assert_eq!("-65536", rust_enum_constant_value(repr, -65536, 0xFFFF_0000));
+		assert_eq!("-2147483648", rust_enum_constant_value(repr, -2147483648, 0x8000_0000));
+		assert_eq!("7", rust_enum_constant_value(repr, 7, 7));
+
+		// `enum : int64_t`
+		let repr = rust_repr_for_underlying(8, false);
+		assert_eq!("4294967296", rust_enum_constant_value(repr, 0x1_0000_0000, 0x1_0000_0000));
+		assert_eq!(
+			"-4294967296",
+			rust_enum_constant_value(repr, -0x1_0000_0000, (-0x1_0000_0000_i64) as u64)
+		);
+
+		// `enum : uint64_t`
+		let repr = rust_repr_for_underlying(8, true);
+		assert_eq!("4294967296", rust_enum_constant_value(repr, 0x1_0000_0000, 0x1_0000_0000));
+		assert_eq!(
+			"18446744069414584320",
+			rust_enum_constant_value(repr, (0xFFFF_FFFF_0000_0000_u64) as i64, 0xFFFF_FFFF_0000_0000)
+		);
+	}
+}
@@ -169,6 +169,14 @@ impl<'tu, 'ge> Field<'tu, 'ge> {
 		ARGUMENT_NAMES_USERDATA.contains(self.cpp_name(CppNameStyle::Declaration).as_ref()) && self.type_ref().kind().is_void_ptr()
 	}
 
+	/// whether field is a C bitfield (e.g. `unsigned flag : 1`), such fields have no address and no Rust layout equivalent
+	pub fn is_bit_field(&self) -> bool {
+		match self {
+			Self::Clang { entity, .. } => entity.is_bit_field(),
+			Self::Desc(_) => false,
+		}
+	}
+
 	pub fn slice_arg_eligibility(&self) -> SliceArgEligibility {
 		self
 			.type_ref()
@@ -494,6 +494,15 @@ impl<'tu, 'ge> Func<'tu, 'ge> {
 		}
 	}
 
+	/// Return or argument type that's nested deeper than [settings::MAX_TYPE_NESTING_DEPTH], such functions are not generated
+	pub fn too_deeply_nested_type(&self) -> Option<TypeRef<'tu, 'ge>> {
+		let max_depth = *settings::MAX_TYPE_NESTING_DEPTH;
+		Some(self.return_type_ref())
+			.into_iter()
+			.chain(self.arguments().iter().map(|a| a.type_ref().into_owned()))
+			.find(|type_ref| type_ref.exceeds_nesting_depth(max_depth))
+	}
+
 	pub fn arguments(&self) -> Cow<[Field<'tu, 'ge>]> {
 		match self {
 			&Self::Clang { entity, gen_env, .. } => {
@@ -628,6 +637,11 @@ impl Element for Func<'_, '_> {
 			return ExcludeKind::Included.with_is_excluded(|| settings::FUNC_EXCLUDE.contains(self.identifier().as_str()));
 		}
 		let kind = self.kind();
+		let is_closure_arg_allowed = settings::FUNC_CLOSURE_ARG.contains_key(&func_id);
+		let is_arg_ignored = |a: &Field| {
+			let type_ref = a.type_ref();
+			type_ref.exclude_kind().is_ignored() && !(is_closure_arg_allowed && type_ref.is_closure())
+		};
 		DefaultElement::exclude_kind(self)
 			.with_reference_exclude_kind(|| self.return_type_ref().exclude_kind())
 			.with_is_excluded(|| {
@@ -639,7 +653,8 @@ impl Element for Func<'_, '_> {
 				is_unavailable
 					|| settings::FUNC_EXCLUDE.contains(identifier.as_str())
 					|| self.is_generic()
-					|| self.arguments().iter().any(|a| a.type_ref().exclude_kind().is_ignored())
+					|| self.too_deeply_nested_type().is_some()
+					|| self.arguments().iter().any(is_arg_ignored)
 					|| kind.as_operator().map_or(false, |(_, kind)| match kind {
 						OperatorKind::Unsupported => true,
 						// filter out postfix version of ++ and --: https://en.cppreference.com/w/cpp/language/operator_incdec
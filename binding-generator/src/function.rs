@@ -9,6 +9,7 @@ use crate::{Element, Field, GeneratorEnv, IteratorExt, TypeRef};
 #[derive(Clone)]
 pub struct Function<'tu, 'ge> {
 	type_ref: Type<'tu>,
+	class_type_ref: Option<Type<'tu>>,
 	parent_entity: Entity<'tu>,
 	gen_env: &'ge GeneratorEnv<'tu>,
 }
@@ -17,11 +18,32 @@ impl<'tu, 'ge> Function<'tu, 'ge> {
 	pub fn new(type_ref: Type<'tu>, parent_entity: Entity<'tu>, gen_env: &'ge GeneratorEnv<'tu>) -> Self {
 		Self {
 			type_ref,
+			class_type_ref: None,
 			parent_entity,
 			gen_env,
 		}
 	}
 
+	/// Pointer to the member function of the class `class_type_ref`, i.e. `R (C::*)(Args)`
+	pub fn new_member(
+		type_ref: Type<'tu>,
+		class_type_ref: Type<'tu>,
+		parent_entity: Entity<'tu>,
+		gen_env: &'ge GeneratorEnv<'tu>,
+	) -> Self {
+		Self {
+			type_ref,
+			class_type_ref: Some(class_type_ref),
+			parent_entity,
+			gen_env,
+		}
+	}
+
+	/// Returns true if this is a pointer to the member function rather than to the free function
+	pub fn is_member(&self) -> bool {
+		self.class_type_ref.is_some()
+	}
+
 	pub fn arguments(&self) -> Vec<Field<'tu, 'ge>> {
 		let mut out = Vec::with_capacity(10);
 		self.parent_entity.visit_children(|c, _| {
@@ -62,13 +84,16 @@ impl Element for Function<'_, '_> {
 			.map(|a| a.type_ref().cpp_name_ext(CppNameStyle::Reference, "", false).into_owned())
 			.join(", ");
 		let ret = self.return_type();
-		format!("{ret} (*)({args})", args = args, ret = ret.cpp_name(CppNameStyle::Reference)).into()
+		let class = self
+			.class_type_ref
+			.map(|cls| TypeRef::new(cls, self.gen_env).cpp_name(CppNameStyle::Reference).into_owned());
+		render_function_pointer(&ret.cpp_name(CppNameStyle::Reference), class.as_deref(), &args).into()
 	}
 }
 
 impl PartialEq for Function<'_, '_> {
 	fn eq(&self, other: &Self) -> bool {
-		self.type_ref == other.type_ref && self.parent_entity == other.parent_entity
+		self.type_ref == other.type_ref && self.class_type_ref == other.class_type_ref && self.parent_entity == other.parent_entity
 	}
 }
 
@@ -87,3 +112,47 @@ impl fmt::Debug for Function<'_, '_> {
 			.finish()
 	}
 }
+
+/// Renders the unnamed C++ pointer to function type, `class` is set for the member function pointers
+fn render_function_pointer(ret: &str, class: Option<&str>, args: &str) -> String {
+	if let Some(class) = class {
+		format!("{ret} ({class}::*)({args})")
+	} else {
+		format!("{ret} (*)({args})")
+	}
+}
+
+/// Inserts `name` into the unnamed pointer to function type rendered by [render_function_pointer], returns `None` if `typ`
+/// doesn't look like one
+pub fn name_function_pointer(typ: &str, name: &str) -> Option<String> {
+	let free_star = typ.find("(*)").map(|pos| pos + 1);
+	let member_star = typ.find("::*)").map(|pos| pos + 2);
+	let star = free_star.into_iter().chain(member_star).min()?;
+	let mut out = typ.to_string();
+	out.insert_str(star + 1, name);
+	Some(out)
+}
+
+#[cfg(test)]
+mod test {
+	use super::{name_function_pointer, render_function_pointer};
+
+	#[test]
+	fn function_pointer() {
+		let free = render_function_pointer("int", None, "const cv::Mat&, void*");
+		assert_eq!("int (*)(const cv::Mat&, void*)", free);
+		assert_eq!(
+			"int (*callback)(const cv::Mat&, void*)",
+			name_function_pointer(&free, "callback").unwrap()
+		);
+
+		let member = render_function_pointer("void", Some("cv::Algorithm"), "int");
+		assert_eq!("void (cv::Algorithm::*)(int)", member);
+		assert_eq!(
+			"void (cv::Algorithm::*setter)(int)",
+			name_function_pointer(&member, "setter").unwrap()
+		);
+
+		assert_eq!(None, name_function_pointer("cv::Mat", "m"));
+	}
+}
@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::collections::HashSet;
 use std::fs::File;
 use std::io::BufReader;
 use std::path::{Path, PathBuf};
@@ -76,6 +77,8 @@ struct OpenCvWalker<'tu, 'r, V: GeneratorVisitor> {
 	opencv_module_header_dir: &'r Path,
 	visitor: V,
 	gen_env: GeneratorEnv<'tu>,
+	/// Types exceeding [settings::MAX_TYPE_NESTING_DEPTH] that were already reported
+	reported_nested_types: HashSet<String>,
 }
 
 impl<'tu, V: GeneratorVisitor> EntityWalkerVisitor<'tu> for OpenCvWalker<'tu, '_, V> {
@@ -127,9 +130,11 @@ impl<'tu, V: GeneratorVisitor> EntityWalkerVisitor<'tu> for OpenCvWalker<'tu, '_
 			EntityKind::ClassDecl
 			| EntityKind::ClassTemplate
 			| EntityKind::ClassTemplatePartialSpecialization
-			| EntityKind::StructDecl => Self::process_class(&mut self.visitor, &mut self.gen_env, entity),
+			| EntityKind::StructDecl => Self::process_class(&mut self.visitor, &mut self.gen_env, &mut self.reported_nested_types, entity),
 			EntityKind::EnumDecl => Self::process_enum(&mut self.visitor, entity),
-			EntityKind::FunctionDecl => Self::process_func(&mut self.visitor, &mut self.gen_env, entity),
+			EntityKind::FunctionDecl => {
+				Self::process_func(&mut self.visitor, &mut self.gen_env, &mut self.reported_nested_types, entity)
+			}
 			EntityKind::TypedefDecl | EntityKind::TypeAliasDecl => {
 				Self::process_typedef(&mut self.visitor, &mut self.gen_env, entity)
 			}
@@ -154,6 +159,21 @@ impl<'tu, 'r, V: GeneratorVisitor> OpenCvWalker<'tu, 'r, V> {
 			opencv_module_header_dir,
 			visitor,
 			gen_env,
+			reported_nested_types: HashSet::new(),
+		}
+	}
+
+	/// Warn once per type about the functions that are skipped because they use too deeply nested types
+	fn report_too_deeply_nested(reported_nested_types: &mut HashSet<String>, func: &Func) {
+		if let Some(type_ref) = func.too_deeply_nested_type() {
+			let type_name = type_ref.cpp_name(CppNameStyle::Reference).into_owned();
+			if reported_nested_types.insert(type_name) {
+				eprintln!(
+					"=== WARNING: {} uses a type nested deeper than {} levels, it and other functions using that type will be skipped",
+					func.cpp_name(CppNameStyle::Reference),
+					*settings::MAX_TYPE_NESTING_DEPTH
+				);
+			}
 		}
 	}
 
@@ -164,10 +184,18 @@ impl<'tu, 'r, V: GeneratorVisitor> OpenCvWalker<'tu, 'r, V> {
 		}
 	}
 
-	fn process_class(visitor: &mut V, gen_env: &mut GeneratorEnv<'tu>, class_decl: Entity<'tu>) {
+	fn process_class(
+		visitor: &mut V,
+		gen_env: &mut GeneratorEnv<'tu>,
+		reported_nested_types: &mut HashSet<String>,
+		class_decl: Entity<'tu>,
+	) {
 		if gen_env.get_export_config(class_decl).is_some() {
 			let cls = Class::new(class_decl, gen_env);
 			if cls.exclude_kind().is_included() {
+				for method in cls.methods() {
+					Self::report_too_deeply_nested(reported_nested_types, &method);
+				}
 				cls.generated_types().into_iter().for_each(|dep| {
 					visitor.visit_generated_type(dep);
 				});
@@ -183,7 +211,7 @@ impl<'tu, 'r, V: GeneratorVisitor> OpenCvWalker<'tu, 'r, V> {
 							ClassKindOverride::Boxed
 						};
 					}
-					Self::process_class(visitor, gen_env, sub_cls);
+					Self::process_class(visitor, gen_env, reported_nested_types, sub_cls);
 					WalkAction::Continue
 				});
 				class_decl.walk_typedefs_while(|tdef| {
@@ -214,7 +242,12 @@ impl<'tu, 'r, V: GeneratorVisitor> OpenCvWalker<'tu, 'r, V> {
 		}
 	}
 
-	fn process_func(visitor: &mut V, gen_env: &mut GeneratorEnv<'tu>, func_decl: Entity<'tu>) {
+	fn process_func(
+		visitor: &mut V,
+		gen_env: &mut GeneratorEnv<'tu>,
+		reported_nested_types: &mut HashSet<String>,
+		func_decl: Entity<'tu>,
+	) {
 		if let Some(e) = gen_env.get_export_config(func_decl) {
 			let func = Func::new(func_decl, gen_env);
 			let func = if let Some(func_fact) = settings::FUNC_REPLACE.get(&func.func_id()) {
@@ -222,7 +255,14 @@ impl<'tu, 'r, V: GeneratorVisitor> OpenCvWalker<'tu, 'r, V> {
 			} else {
 				func
 			};
+			Self::report_too_deeply_nested(reported_nested_types, &func);
 			if func.exclude_kind().is_included() {
+				if func.arguments().iter().any(|a| a.type_ref().kind().is_opaque_handle_ptr()) {
+					eprintln!(
+						"=== WARNING: {} takes an opaque handle argument, it will be generated as unsafe with a raw pointer",
+						func.cpp_name(CppNameStyle::Reference)
+					);
+				}
 				let func_id = func.func_id().make_static();
 				let mut processor = |spec| {
 					let func = if e.only_generated_types {
@@ -1,7 +1,8 @@
 use std::borrow::Cow;
 
+use crate::function::name_function_pointer;
 use crate::type_ref::{TemplateArg, TypeRef, TypeRefDesc, TypeRefKind};
-use crate::{CppNameStyle, Element, IteratorExt, StringExt};
+use crate::{settings, CppNameStyle, Element, IteratorExt};
 
 pub trait TypeRefRenderer<'a> {
 	type Recursed: TypeRefRenderer<'a> + Sized;
@@ -17,6 +18,8 @@ pub struct CppRenderer<'s> {
 	/// true for rendering in extern contexts, references are treated as pointers, this is used for declaring the types of
 	/// callbacks in C++ code and for `cpp_safe_id`
 	pub extern_types: bool,
+	/// current nesting level, rendering stops at [settings::MAX_TYPE_NESTING_DEPTH]
+	depth: usize,
 }
 
 impl<'s> CppRenderer<'s> {
@@ -25,6 +28,7 @@ impl<'s> CppRenderer<'s> {
 			name_style,
 			name,
 			extern_types,
+			depth: 0,
 		}
 	}
 }
@@ -33,6 +37,10 @@ impl<'a> TypeRefRenderer<'a> for CppRenderer<'_> {
 	type Recursed = Self;
 
 	fn render<'t>(self, type_ref: &'t TypeRef) -> Cow<'t, str> {
+		if self.depth >= *settings::MAX_TYPE_NESTING_DEPTH {
+			// such types are excluded by TypeRef::exclude_kind(), so the placeholder should only end up in the diagnostics
+			return "...".into();
+		}
 		let cnst = type_ref.inherent_constness().cpp_qual();
 		let (space_name, space_const_name) = if self.name.is_empty() {
 			("".to_string(), "".to_string())
@@ -99,8 +107,8 @@ impl<'a> TypeRefRenderer<'a> for CppRenderer<'_> {
 			}
 			TypeRefKind::Class(cls) => {
 				let mut out = cls.cpp_name(self.name_style).into_owned();
-				if !kind.is_std_string(type_ref.type_hint()) {
-					// fixme prevents emission of std::string<char>
+				if !kind.is_std_or_cv_string(type_ref.type_hint()) {
+					// fixme prevents emission of std::string<char> or cv::String<char>
 					out += &render_cpp_tpl(self, type_ref);
 				}
 				format!("{cnst}{out}{space_name}")
@@ -122,13 +130,9 @@ impl<'a> TypeRefRenderer<'a> for CppRenderer<'_> {
 				format!("{cnst}{generic_name}{space_name}")
 			}
 			TypeRefKind::Function(func) => {
-				let mut typ = func.cpp_name(self.name_style);
-				if typ.contains("(*)") {
-					if !self.name.is_empty() {
-						typ.to_mut()
-							.replacen_in_place("(*)", 1, &format!("(*{name})", name = self.name));
-					}
-					typ.into_owned()
+				let typ = func.cpp_name(self.name_style);
+				if let Some(named) = name_function_pointer(&typ, self.name) {
+					named
 				} else {
 					format!("{typ}{space_name}")
 				}
@@ -145,6 +149,7 @@ impl<'a> TypeRefRenderer<'a> for CppRenderer<'_> {
 			name_style: self.name_style,
 			name: "",
 			extern_types: self.extern_types,
+			depth: self.depth + 1,
 		}
 	}
 }
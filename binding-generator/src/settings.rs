@@ -1,6 +1,7 @@
 // todo add doccomments
 
 use std::collections::{BTreeSet, HashMap, HashSet};
+use std::env;
 
 use once_cell::sync::Lazy;
 
@@ -9,12 +10,17 @@ pub use argument_override::{ARGUMENT_OVERRIDE, ARG_OVERRIDE_SELF, RETURN_OVERRID
 pub use element_exclude_kind::ELEMENT_EXCLUDE_KIND;
 pub use element_export_tweak::ELEMENT_EXPORT_TWEAK;
 pub use force_infallible::FORCE_INFALLIBLE;
+pub use func_builder::FUNC_BUILDER;
 pub use func_cfg_attr::FUNC_CFG_ATTR;
+pub use func_closure_arg::FUNC_CLOSURE_ARG;
 pub use func_exclude::FUNC_EXCLUDE;
 pub use func_inject::{FuncFactory, FUNC_INJECT};
+pub use func_operator_trait::FUNC_OPERATOR_TRAIT;
+pub use func_out_return::FUNC_OUT_RETURN;
 pub use func_rename::FUNC_RENAME;
 pub use func_replace::{FuncInheritFactory, FUNC_REPLACE};
 pub use func_specialize::{TypeRefFactory, FUNC_SPECIALIZE};
+pub use func_tuple_return::FUNC_TUPLE_RETURN;
 pub use func_unsafe::FUNC_UNSAFE;
 pub use generator_module_tweaks::{ModuleTweak, GENERATOR_MODULE_TWEAKS};
 pub use implemented::{
@@ -27,16 +33,32 @@ mod argument_override;
 mod element_exclude_kind;
 mod element_export_tweak;
 mod force_infallible;
+mod func_builder;
 mod func_cfg_attr;
+mod func_closure_arg;
 mod func_exclude;
 mod func_inject;
+mod func_operator_trait;
+mod func_out_return;
 mod func_rename;
 mod func_replace;
 mod func_specialize;
+mod func_tuple_return;
 mod func_unsafe;
 mod generator_module_tweaks;
 mod implemented;
 
+/// Maximum nesting depth of a type (pointers, references, containers and template arguments), the deeper types are not
+/// rendered and the elements referencing them are skipped to protect against the pathological template instantiations
+///
+/// Defaults to 32, can be changed with the `OPENCV_BINDING_GENERATOR_MAX_TYPE_NESTING_DEPTH` environment variable.
+pub static MAX_TYPE_NESTING_DEPTH: Lazy<usize> = Lazy::new(|| {
+	env::var("OPENCV_BINDING_GENERATOR_MAX_TYPE_NESTING_DEPTH")
+		.ok()
+		.and_then(|depth| depth.parse().ok())
+		.unwrap_or(32)
+});
+
 // fixme, generalize, make it use constant::ValueKind
 pub static CONST_TYPE_USIZE: Lazy<HashSet<&str>> = Lazy::new(|| HashSet::from(["Mat_AUTO_STEP"]));
 
@@ -79,6 +101,26 @@ pub static PRIMITIVE_TYPEDEFS: Lazy<HashMap<&str, (&str, &str)>> = Lazy::new(||
 	])
 });
 
+/// System types that are only ever handled through a pointer and are passed to Rust as an opaque `*mut c_void`
+/// cpp_name(Reference)
+pub static OPAQUE_HANDLE_TYPES: Lazy<HashSet<&str>> = Lazy::new(|| HashSet::from(["FILE"]));
+
+/// boxed classes whose fallible methods check that the receiver isn't null and return an error instead of crashing on the
+/// C++ side, element is cpp_name(Reference)
+pub static NULL_RECEIVER_CHECK: Lazy<HashSet<&str>> = Lazy::new(|| HashSet::from(["cv::Algorithm"]));
+
+/// enums that OpenCV is expected to extend with new values, they are marked `#[non_exhaustive]` so that the downstream
+/// `match`es require a wildcard arm, element is cpp_name(Reference)
+pub static NON_EXHAUSTIVE_ENUMS: Lazy<HashSet<&str>> = Lazy::new(HashSet::new);
+
+/// namespace level enums whose values are meant to be combined with `|`, they get an additional `{EnumName}Flags` struct
+/// holding such a combination, key is cpp_name(Reference), value is the Rust module of the enum
+///
+/// `int` function arguments named `flags` (or ending with `Flags`) are typed as `impl Into<{EnumName}Flags>` if their
+/// `@param` documentation mentions exactly one of these enums, e.g. `@param flags Flag that can take values of
+/// cv::ImreadModes`. The flags struct is convertible from `i32` so the calls passing the raw constants keep compiling.
+pub static FLAGS_ENUMS: Lazy<HashMap<&str, &str>> = Lazy::new(|| HashMap::from([("cv::ImreadModes", "imgcodecs")]));
+
 pub static STATIC_MODULES: Lazy<BTreeSet<&str>> = Lazy::new(|| BTreeSet::from(["core", "sys", "types"]));
 
 /// Types that can be used as `Mat` element
@@ -47,6 +47,7 @@ pub static ELEMENT_EXPORT_TWEAK: Lazy<HashMap<&str, fn(ExportConfig) -> Option<E
 		("cv::QtFont", ExportConfig::export as _),
 		("cv::TermCriteria", ExportConfig::simple as _),
 		("cv::RotatedRect", ExportConfig::simple as _), // marked simple since 4.8.0
+		("cv::parallel_for_", ExportConfig::export as _), // std::function overload is static inline
 		("cv::aruco::DetectorParameters", ExportConfig::force_boxed as _), // used in Ptr
 		("cv::aruco::EstimateParameters", ExportConfig::force_boxed as _), // used in Ptr
 		("cv::bioinspired::RetinaParameters", ExportConfig::force_boxed as _),
@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+
+use crate::FuncId;
+
+/// Functions that get an additional `{FuncName}Builder` struct that allows setting the optional arguments one by one with
+/// `with_*` methods instead of passing all of them to a single function call
+///
+/// key: Func.func_id()
+/// value: map of the C++ name of the optional argument to the Rust expression for its default value, only needed for the
+/// defaults that can't be derived from the C++ ones (see `rust_builder_default`)
+pub static FUNC_BUILDER: Lazy<HashMap<FuncId, HashMap<&str, &str>>> = Lazy::new(|| {
+	HashMap::from([
+		// video
+		(
+			FuncId::new_mut(
+				"cv::calcOpticalFlowPyrLK",
+				[
+					"prevImg",
+					"nextImg",
+					"prevPts",
+					"nextPts",
+					"status",
+					"err",
+					"winSize",
+					"maxLevel",
+					"criteria",
+					"flags",
+					"minEigThreshold",
+				],
+			),
+			HashMap::from([(
+				"criteria",
+				"core::TermCriteria { typ: core::TermCriteria_COUNT + core::TermCriteria_EPS, max_count: 30, epsilon: 0.01 }",
+			)]),
+		),
+	])
+});
@@ -0,0 +1,21 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+
+use crate::FuncId;
+
+/// Functions that only call their `std::function` arguments before returning (possibly from several threads at once), so those
+/// arguments can be passed as borrowed Rust closures: `&(dyn Fn(...) + Sync)`
+///
+/// key: Func.func_id()
+/// value: new Rust name of the function ("+" will be replaced by the old name), the closure version usually clashes with the
+/// overload taking an OpenCV class
+pub static FUNC_CLOSURE_ARG: Lazy<HashMap<FuncId, &str>> = Lazy::new(|| {
+	HashMap::from([
+		// core
+		(
+			FuncId::new_mut("cv::parallel_for_", ["range", "functor", "nstripes"]),
+			"parallel_for_closure",
+		),
+	])
+});
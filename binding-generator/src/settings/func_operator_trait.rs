@@ -0,0 +1,21 @@
+use std::collections::HashSet;
+
+use once_cell::sync::Lazy;
+
+use crate::FuncId;
+
+/// Free function operators (`+`, `-`, `*`, `/`, `&`, `|`, `^`) that additionally get the corresponding `std::ops` trait
+/// implemented for their argument types, element is Func.func_id()
+///
+/// Both arguments must be either boxed classes passed by const reference or `Copy` types passed by value. Make sure the
+/// listed operators don't overlap with the manual implementations, e.g. the `Mat` ones in `core`.
+pub static FUNC_OPERATOR_TRAIT: Lazy<HashSet<FuncId>> = Lazy::new(|| {
+	HashSet::from([
+		// core, Mat doesn't have manual bitwise operators
+		FuncId::new_mut("cv::operator&", ["a", "b"]),
+		FuncId::new_mut("cv::operator|", ["a", "b"]),
+		FuncId::new_mut("cv::operator^", ["a", "b"]),
+		// gapi, GMat + GMat, GMat + GScalar and GScalar + GMat
+		FuncId::new_mut("cv::operator+", ["lhs", "rhs"]),
+	])
+});
@@ -0,0 +1,17 @@
+use std::collections::HashSet;
+
+use once_cell::sync::Lazy;
+
+use crate::FuncId;
+
+/// Void functions with a single output argument that get an additional `_out` companion returning that argument instead of
+/// taking it by `&mut`, element is Func.func_id()
+///
+/// The output argument is either an `OutputArray` (returned as `Mat`) or a mutable reference to a primitive.
+pub static FUNC_OUT_RETURN: Lazy<HashSet<FuncId>> = Lazy::new(|| {
+	HashSet::from([
+		// imgproc
+		FuncId::new_mut("cv::cvtColor", ["src", "dst", "code", "dstCn"]),
+		FuncId::new_mut("cv::GaussianBlur", ["src", "dst", "ksize", "sigmaX", "sigmaY", "borderType"]),
+	])
+});
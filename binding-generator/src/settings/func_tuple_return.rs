@@ -0,0 +1,19 @@
+use std::collections::HashSet;
+
+use once_cell::sync::Lazy;
+
+use crate::FuncId;
+
+/// Functions that get an additional `_tuple` companion that returns all of their `OutputArray` arguments as a tuple of `Mat`s
+/// instead of taking them as `&mut` arguments, element is Func.func_id()
+pub static FUNC_TUPLE_RETURN: Lazy<HashSet<FuncId>> = Lazy::new(|| {
+	HashSet::from([
+		// calib3d
+		FuncId::new_mut("cv::decomposeEssentialMat", ["E", "R1", "R2", "t"]),
+		FuncId::new_mut(
+			"cv::decomposeProjectionMatrix",
+			["projMatrix", "cameraMatrix", "rotMatrix", "transVect"],
+		),
+		FuncId::new_mut("cv::RQDecomp3x3", ["src", "mtxR", "mtxQ"]),
+	])
+});
@@ -1,10 +1,16 @@
 use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard, PoisonError};
 
+use clang::{Clang, EntityKind, Index, Unsaved};
 use regex::bytes::Regex;
 
 use crate::string_ext::Indent;
-use crate::type_ref::FishStyle;
-use crate::{StrExt, StringExt};
+use crate::type_ref::{CppNameStyle, FishStyle, NameStyle, TypeRefKind};
+use crate::typedef::NewTypedefResult;
+use crate::writer::rust_native::element::{RustElement, RustNativeGeneratedElement};
+use crate::writer::rust_native::type_ref::TypeRefExt;
+use crate::{opencv_module_from_path, Element, EntityExt, Func, GeneratorEnv, StrExt, StringExt, Typedef, WalkAction};
 
 #[test]
 fn replace_in_place() {
@@ -477,6 +483,23 @@ fn module() {
 	assert_eq!("", "::".module());
 }
 
+#[test]
+fn module_from_path() {
+	fn module(path: &str) -> Option<&str> {
+		opencv_module_from_path(Path::new(path))
+	}
+
+	assert_eq!(Some("imgproc"), module("/usr/include/opencv4/opencv2/imgproc.hpp"));
+	assert_eq!(
+		Some("imgproc"),
+		module("/usr/include/opencv4/opencv2/imgproc/segmentation.hpp")
+	);
+	assert_eq!(Some("calib3d"), module("/usr/include/opencv4/opencv2/calib3d.hpp"));
+	assert_eq!(Some("core"), module("/usr/include/opencv4/opencv2/core/mat.hpp"));
+	assert_eq!(Some("dnn"), module("/opt/opencv-rust/src_cpp/dnn.hpp"));
+	assert_eq!(None, module("/usr/include/stdio.h"));
+}
+
 #[test]
 fn fish_apply() {
 	let rust_fish_fullname = "crate::VecN::<3, 2>";
@@ -491,3 +514,207 @@ fn fish_apply() {
 	assert_eq!("crate::VecN", FishStyle::No.apply(rust_no_generics_fullname));
 	assert_eq!("crate::VecN", FishStyle::Turbo.apply(rust_no_generics_fullname));
 }
+
+/// Only a single `Clang` instance can exist at a time, so the tests parsing headers hold this lock while using it
+pub fn clang_lock() -> MutexGuard<'static, ()> {
+	static CLANG: Mutex<()> = Mutex::new(());
+	CLANG.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[test]
+fn anonymous_struct_typedef() {
+	const HEADER: &str = "
+namespace cv {
+	typedef struct {
+		int x, y;
+		float score;
+	} Box;
+
+	void drawBox(const Box& box);
+}
+";
+	let _clang_lock = clang_lock();
+	let clang = Clang::new().expect("Can't initialize clang");
+	let index = Index::new(&clang, false, false);
+	let tu = index
+		.parser("anonymous_struct.hpp")
+		.unsaved(&[Unsaved::new("anonymous_struct.hpp", HEADER)])
+		.arguments(&["-xc++", "-std=c++14"])
+		.parse()
+		.expect("Can't parse header");
+	let root = tu.get_entity();
+	let gen_env = GeneratorEnv::new(root, "core");
+
+	let mut typedef = None;
+	let mut draw_box = None;
+	root.walk_children_while(|ns| {
+		ns.walk_children_while(|child| {
+			match child.get_kind() {
+				EntityKind::TypedefDecl => typedef = Some(child),
+				EntityKind::FunctionDecl => draw_box = Some(Func::new(child, &gen_env)),
+				_ => {}
+			}
+			WalkAction::Continue
+		});
+		WalkAction::Interrupt
+	});
+	let typedef = typedef.expect("Can't find typedef");
+	let draw_box = draw_box.expect("Can't find drawBox");
+
+	// the anonymous struct becomes a class named after the typedef, so a function taking `Box` binds it as a named struct
+	let NewTypedefResult::Class(cls) = Typedef::try_new(typedef, &gen_env) else {
+		panic!("Anonymous struct typedef must be converted to a Class");
+	};
+	assert_eq!("cv::Box", cls.cpp_name(CppNameStyle::Reference));
+	assert_eq!("Box", cls.cpp_name(CppNameStyle::Declaration));
+	assert_eq!(
+		vec!["x", "y", "score"],
+		cls.fields()
+			.iter()
+			.map(|f| f.cpp_name(CppNameStyle::Declaration).into_owned())
+			.collect::<Vec<_>>()
+	);
+
+	let args = draw_box.arguments();
+	let [arg] = args.as_ref() else {
+		panic!("drawBox must have a single argument, got: {args:#?}");
+	};
+	let arg_type_ref = arg.type_ref();
+	let arg_type_kind = arg_type_ref.kind();
+	let arg_cls = arg_type_kind
+		.as_reference()
+		.and_then(|inner| inner.kind().as_class().map(|cls| cls.into_owned()))
+		.expect("drawBox argument must be a reference to a class");
+	assert_eq!("cv::Box", arg_cls.cpp_name(CppNameStyle::Reference));
+}
+
+#[test]
+fn std_pair() {
+	const HEADER: &str = "
+namespace std {
+	template<typename T1, typename T2> struct pair {
+		T1 first;
+		T2 second;
+	};
+}
+
+namespace cv {
+	std::pair<int, float> flatPair();
+	std::pair<int, std::pair<float, double>> nestedPair();
+}
+";
+	let _clang_lock = clang_lock();
+	let clang = Clang::new().expect("Can't initialize clang");
+	let index = Index::new(&clang, false, false);
+	let tu = index
+		.parser("std_pair.hpp")
+		.unsaved(&[Unsaved::new("std_pair.hpp", HEADER)])
+		.arguments(&["-xc++", "-std=c++14"])
+		.parse()
+		.expect("Can't parse header");
+	let root = tu.get_entity();
+	let gen_env = GeneratorEnv::new(root, "core");
+
+	let mut funcs = vec![];
+	root.walk_children_while(|ns| {
+		if ns.get_name().as_deref() == Some("cv") {
+			ns.walk_children_while(|child| {
+				if child.get_kind() == EntityKind::FunctionDecl {
+					funcs.push(Func::new(child, &gen_env));
+				}
+				WalkAction::Continue
+			});
+		}
+		WalkAction::Continue
+	});
+	let [flat, nested] = funcs.as_slice() else {
+		panic!("Expected 2 functions, got: {funcs:#?}");
+	};
+
+	let flat = flat.return_type_ref();
+	assert!(matches!(flat.kind().as_ref(), TypeRefKind::StdTuple(_)));
+	assert_eq!("std::pair<int, float>", flat.cpp_name(CppNameStyle::Reference));
+	assert_eq!("core::Tuple<(i32, f32)>", flat.rust_name(NameStyle::ref_()));
+
+	let nested = nested.return_type_ref();
+	let TypeRefKind::StdTuple(tuple) = nested.kind().into_owned() else {
+		panic!("std::pair must be rendered as a tuple");
+	};
+	let [first, second] = tuple.elements().try_into().expect("Pair has 2 elements");
+	assert_eq!("int", first.cpp_name(CppNameStyle::Reference));
+	assert!(matches!(second.kind().as_ref(), TypeRefKind::StdTuple(_)));
+	assert_eq!(
+		"std::pair<int, std::pair<float, double>>",
+		nested.cpp_name(CppNameStyle::Reference)
+	);
+	assert_eq!(
+		"core::Tuple<(i32, core::Tuple<(f32, f64)>)>",
+		nested.rust_name(NameStyle::ref_())
+	);
+}
+
+#[test]
+fn std_function_closure_arg() {
+	const HEADER: &str = "
+namespace std {
+	template<typename> class function;
+	template<typename R, typename... Args> class function<R(Args...)> {
+	public:
+		function();
+	};
+}
+
+namespace cv {
+	void parallel_for_(int range, std::function<void(int, const double&)> functor, double nstripes);
+	void parallel_for_(int range, std::function<int(int)> functor, double nstripes);
+	void forEach(std::function<void(int)> functor);
+}
+";
+	let _clang_lock = clang_lock();
+	let clang = Clang::new().expect("Can't initialize clang");
+	let index = Index::new(&clang, false, false);
+	let tu = index
+		.parser("std_function.hpp")
+		.unsaved(&[Unsaved::new("std_function.hpp", HEADER)])
+		.arguments(&["-xc++", "-std=c++14"])
+		.parse()
+		.expect("Can't parse header");
+	let root = tu.get_entity();
+	let gen_env = GeneratorEnv::new(root, "core");
+
+	let mut funcs = vec![];
+	root.walk_children_while(|ns| {
+		if ns.get_name().as_deref() == Some("cv") {
+			ns.walk_children_while(|child| {
+				if child.get_kind() == EntityKind::FunctionDecl {
+					funcs.push(Func::new(child, &gen_env));
+				}
+				WalkAction::Continue
+			});
+		}
+		WalkAction::Continue
+	});
+	let [closure, non_void, not_listed] = funcs.as_slice() else {
+		panic!("Expected 3 functions, got: {funcs:#?}");
+	};
+
+	// only the function listed in FUNC_CLOSURE_ARG takes a closure, and only if the signature of std::function is supported
+	assert!(closure.exclude_kind().is_included());
+	assert!(!non_void.exclude_kind().is_included());
+	assert!(!not_listed.exclude_kind().is_included());
+
+	assert!(closure.arguments()[1].type_ref().is_closure());
+	assert_eq!("parallel_for_closure", closure.rust_leafname(FishStyle::No));
+	let rust = closure.gen_rust("4.9.0");
+	assert!(rust
+		.contains("pub fn parallel_for_closure(range: i32, functor: &(dyn Fn(i32, f64) + Sync), nstripes: f64) -> Result<()> {"));
+	assert!(
+		rust.contains("closure_arg!(functor_trampoline(arg_0: i32 => i32, arg_1: f64 => f64) => functor_userdata => functor);")
+	);
+	let rust_externs = closure.gen_rust_externs();
+	assert!(rust_externs
+		.contains("functor: unsafe extern \"C\" fn(i32, f64, *mut c_void), functor_userdata: *mut c_void, nstripes: f64"));
+	let cpp = closure.gen_cpp();
+	assert!(cpp.contains("void (*functor)(int, double, void*), void* functor_userdata, double nstripes"));
+	assert!(cpp.contains("[=](const int& arg_0, const double& arg_1) { functor(arg_0, arg_1, functor_userdata); }"));
+}
@@ -7,28 +7,36 @@ use crate::element::ExcludeKind;
 use crate::type_ref::{Constness, CppNameStyle, TemplateArg};
 use crate::{DefaultElement, Element, EntityElement, GeneratorEnv, TypeRef};
 
+/// C++ name of the tuple-like standard template (`tuple` or `pair`) for the class with the fully qualified name `cpp_refname`
+///
+/// The name is matched anywhere after `std::` to also handle the inline namespaces like `std::__1::pair`.
+pub fn std_tuple_type(cpp_refname: &str) -> Option<&'static str> {
+	if !cpp_refname.starts_with("std::") {
+		None
+	} else if cpp_refname.contains("::tuple") {
+		Some("tuple")
+	} else if cpp_refname.contains("::pair") {
+		Some("pair")
+	} else {
+		None
+	}
+}
+
 #[derive(Clone)]
 pub struct Tuple<'tu, 'ge> {
 	type_ref: Type<'tu>,
 	gen_env: &'ge GeneratorEnv<'tu>,
-	/// true for C++ representation as `pair` instead of `tuple`
+	/// C++ name of the template, `tuple` or `pair`
 	tuple_type: &'static str,
 }
 
 impl<'tu, 'ge> Tuple<'tu, 'ge> {
-	pub fn new(type_ref: Type<'tu>, gen_env: &'ge GeneratorEnv<'tu>) -> Self {
-		Self {
-			type_ref,
-			gen_env,
-			tuple_type: "tuple",
-		}
-	}
-
-	pub fn pair(type_ref: Type<'tu>, gen_env: &'ge GeneratorEnv<'tu>) -> Self {
+	/// `tuple_type` is the C++ name of the template as returned by [std_tuple_type]
+	pub fn new(type_ref: Type<'tu>, tuple_type: &'static str, gen_env: &'ge GeneratorEnv<'tu>) -> Self {
 		Self {
 			type_ref,
 			gen_env,
-			tuple_type: "pair",
+			tuple_type,
 		}
 	}
 
@@ -115,3 +123,19 @@ impl fmt::Debug for Tuple<'_, '_> {
 			.finish()
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use super::std_tuple_type;
+
+	#[test]
+	fn tuple_type() {
+		assert_eq!(Some("pair"), std_tuple_type("std::pair"));
+		assert_eq!(Some("pair"), std_tuple_type("std::__1::pair"));
+		assert_eq!(Some("tuple"), std_tuple_type("std::tuple"));
+		assert_eq!(Some("tuple"), std_tuple_type("std::__1::tuple"));
+		assert_eq!(None, std_tuple_type("std::vector"));
+		assert_eq!(None, std_tuple_type("cv::pair"));
+		assert_eq!(None, std_tuple_type("pair"));
+	}
+}
@@ -106,6 +106,8 @@ impl<'tu, 'ge> TypeRef<'tu, 'ge> {
 			))
 		} else if let Some(primitive_typeref) = TypeRefDesc::try_primitive(cpp_refname) {
 			primitive_typeref
+		} else if let Some(handle_typeref) = TypeRefDesc::try_opaque_handle(cpp_refname) {
+			handle_typeref
 		} else {
 			let simplicity = settings::DATA_TYPES
 				.contains(cpp_refname)
@@ -236,16 +238,45 @@ impl<'tu, 'ge> TypeRef<'tu, 'ge> {
 		}
 	}
 
+	/// Whether the type nests other types deeper than `max_depth` levels, a non-nested type has the depth of 1
+	///
+	/// Stops descending into the nested types as soon as the limit is reached so it's safe to call on the recursive types.
+	pub fn exceeds_nesting_depth(&self, max_depth: usize) -> bool {
+		let Some(max_depth) = max_depth.checked_sub(1) else {
+			return true;
+		};
+		match self.kind().as_ref() {
+			TypeRefKind::Array(inner, ..)
+			| TypeRefKind::Pointer(inner)
+			| TypeRefKind::Reference(inner)
+			| TypeRefKind::RValueReference(inner) => inner.exceeds_nesting_depth(max_depth),
+			TypeRefKind::StdVector(vec) => vec.element_type().exceeds_nesting_depth(max_depth),
+			TypeRefKind::StdTuple(tuple) => tuple.elements().iter().any(|e| e.exceeds_nesting_depth(max_depth)),
+			TypeRefKind::SmartPtr(ptr) => ptr.pointee().exceeds_nesting_depth(max_depth),
+			TypeRefKind::Class(_) => self
+				.template_specialization_args()
+				.iter()
+				.filter_map(TemplateArg::as_typename)
+				.any(|t| t.exceeds_nesting_depth(max_depth)),
+			_ => false,
+		}
+	}
+
 	pub fn exclude_kind(&self) -> ExcludeKind {
 		match self.kind().as_ref() {
 			TypeRefKind::Generic(_) | TypeRefKind::Ignored => ExcludeKind::Ignored,
+			// member function pointers can't be passed as Rust callbacks
+			TypeRefKind::Function(func) if func.is_member() => ExcludeKind::Ignored,
 			TypeRefKind::StdVector(vec) => vec.exclude_kind(),
 			TypeRefKind::StdTuple(tuple) => tuple.exclude_kind(),
 			TypeRefKind::Array(inner, ..) => ExcludeKind::Included.with_is_ignored(|| !inner.kind().is_copy(inner.type_hint())),
+			TypeRefKind::Pointer(inner) if inner.kind().is_opaque_handle() => ExcludeKind::Included,
 			TypeRefKind::Pointer(inner) | TypeRefKind::Reference(inner) | TypeRefKind::RValueReference(inner) => {
 				inner.exclude_kind()
 			}
 			TypeRefKind::SmartPtr(ptr) => ptr.exclude_kind(),
+			// std::function is only passed as a Rust closure to the functions in FUNC_CLOSURE_ARG, see [Class::is_std_function]
+			TypeRefKind::Class(cls) if cls.is_std_function() => ExcludeKind::Ignored,
 			TypeRefKind::Class(cls) => cls.exclude_kind(),
 			TypeRefKind::Typedef(tdef) => tdef.exclude_kind(),
 			_ => settings::ELEMENT_EXCLUDE_KIND
@@ -330,6 +361,15 @@ impl<'tu, 'ge> TypeRef<'tu, 'ge> {
 			.map_or_else(|| self.is_data_type(), |vec| vec.element_type().is_data_type())
 	}
 
+	/// True for the `std::function` that can be passed from Rust as a closure, see [Class::std_function_closure_args]
+	pub fn is_closure(&self) -> bool {
+		self
+			.source()
+			.kind()
+			.as_class()
+			.map_or(false, |cls| cls.std_function_closure_args().is_some())
+	}
+
 	pub fn template_specialization_args(&self) -> Cow<[TemplateArg<'tu, 'ge>]> {
 		match self {
 			&Self::Clang { type_ref, gen_env, .. } => type_ref.template_specialization_args(gen_env).into(),
@@ -8,7 +8,7 @@ use regex::bytes::{Captures, Regex};
 use crate::class::ClassDesc;
 use crate::function::Function;
 use crate::smart_ptr::{SmartPtr, SmartPtrDesc};
-use crate::tuple::Tuple;
+use crate::tuple::{std_tuple_type, Tuple};
 use crate::type_ref::{Constness, TemplateArg, TypeRef, TypeRefKind, TypeRefTypeHint};
 use crate::typedef::NewTypedefResult;
 use crate::vector::{Vector, VectorDesc};
@@ -66,6 +66,13 @@ impl<'tu, 'ge> TypeRefDesc<'tu, 'ge> {
 			.map(|type_ref_kind| TypeRef::new_desc(TypeRefDesc::new(type_ref_kind, Constness::Mut)))
 	}
 
+	/// Opaque system handle type like `FILE` that's passed to Rust as `c_void`
+	pub fn try_opaque_handle(cpp_name: &str) -> Option<TypeRef<'tu, 'ge>> {
+		settings::OPAQUE_HANDLE_TYPES
+			.get(cpp_name)
+			.map(|cpp| TypeRef::new_desc(TypeRefDesc::new(TypeRefKind::Primitive("c_void", cpp), Constness::Mut)))
+	}
+
 	pub fn void() -> TypeRef<'tu, 'ge> {
 		Self::try_primitive("void").expect("Static primitive type")
 	}
@@ -123,6 +130,11 @@ impl<'tu, 'ge> TypeRefDesc<'tu, 'ge> {
 		TypeRef::new_class(ClassDesc::cv_size())
 	}
 
+	/// `cv::TermCriteria`
+	pub fn cv_term_criteria() -> TypeRef<'tu, 'ge> {
+		TypeRef::new_class(ClassDesc::cv_term_criteria())
+	}
+
 	/// `cv::Point`
 	pub fn cv_point() -> TypeRef<'tu, 'ge> {
 		TypeRef::new_class(ClassDesc::cv_point())
@@ -203,6 +215,11 @@ impl<'tu, 'ge> TypeRefDesc<'tu, 'ge> {
 		TypeRef::new_class(ClassDesc::cv_string())
 	}
 
+	/// `std::string`
+	pub fn std_string() -> TypeRef<'tu, 'ge> {
+		TypeRef::new_class(ClassDesc::std_string())
+	}
+
 	/// `std::vector<std::vector<double>>`
 	pub fn vector_of_vector_of_double() -> TypeRef<'tu, 'ge> {
 		TypeRef::new_vector(Vector::new_desc(VectorDesc::new(TypeRef::new_vector(Vector::new_desc(
@@ -423,10 +440,8 @@ impl<'tu> ClangTypeExt<'tu> for Type<'tu> {
 						let is_decl = kind == EntityKind::StructDecl || kind == EntityKind::ClassDecl;
 						if cpp_refname.starts_with("std::") && cpp_refname.contains("::vector") {
 							TypeRefKind::StdVector(Vector::new(self, gen_env))
-						} else if cpp_refname.starts_with("std::") && cpp_refname.contains("::tuple") {
-							TypeRefKind::StdTuple(Tuple::new(self, gen_env))
-						} else if cpp_refname.starts_with("std::") && cpp_refname.contains("::pair") {
-							TypeRefKind::StdTuple(Tuple::pair(self, gen_env))
+						} else if let Some(tuple_type) = std_tuple_type(&cpp_refname) {
+							TypeRefKind::StdTuple(Tuple::new(self, tuple_type, gen_env))
 						} else if is_decl && cpp_refname.starts_with("cv::Ptr") {
 							TypeRefKind::SmartPtr(SmartPtr::new(decl, gen_env))
 						} else {
@@ -459,6 +474,8 @@ impl<'tu> ClangTypeExt<'tu> for Type<'tu> {
 					} else if decl.is_system() {
 						if decl_name.starts_with("std::") && decl_name.ends_with("::string") {
 							TypeRefKind::Class(Class::new(decl, gen_env))
+						} else if let Some(cpp) = settings::OPAQUE_HANDLE_TYPES.get(decl_name.as_ref()) {
+							TypeRefKind::Primitive("c_void", cpp)
 						} else {
 							TypeRefKind::Ignored
 						}
@@ -499,7 +516,17 @@ impl<'tu> ClangTypeExt<'tu> for Type<'tu> {
 					)
 				}
 
-				TypeKind::MemberPointer | TypeKind::DependentSizedArray | TypeKind::Half => TypeRefKind::Ignored,
+				TypeKind::MemberPointer => {
+					let pointee = self.get_pointee_type().expect("No pointee type for member pointer");
+					match (pointee.get_kind(), self.get_class_type(), parent_entity) {
+						(TypeKind::FunctionPrototype, Some(class), Some(parent)) => {
+							TypeRefKind::Function(Function::new_member(pointee, class, parent, gen_env))
+						}
+						_ => TypeRefKind::Ignored,
+					}
+				}
+
+				TypeKind::DependentSizedArray | TypeKind::Half => TypeRefKind::Ignored,
 
 				_ => {
 					unreachable!("Can't decide kind: {:#?}", self)
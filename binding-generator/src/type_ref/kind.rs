@@ -8,7 +8,7 @@ use crate::smart_ptr::SmartPtr;
 use crate::tuple::Tuple;
 use crate::type_ref::{Dir, StrEnc, StrType, TypeRef, TypeRefTypeHint};
 use crate::vector::Vector;
-use crate::{Class, CppNameStyle, Element, Enum, Typedef};
+use crate::{settings, Class, CppNameStyle, Element, Enum, Typedef};
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum TypeRefKind<'tu, 'ge> {
@@ -275,6 +275,14 @@ impl<'tu, 'ge> TypeRefKind<'tu, 'ge> {
 		matches!(self.as_string(type_hint), Some((_, StrType::CvString(_))))
 	}
 
+	/// Returns true for both `std::string` and `cv::String` spellings of the string class
+	pub fn is_std_or_cv_string(&self, type_hint: &TypeRefTypeHint) -> bool {
+		matches!(
+			self.as_string(type_hint),
+			Some((_, StrType::StdString(_) | StrType::CvString(_)))
+		)
+	}
+
 	pub fn is_char_ptr_string(&self, type_hint: &TypeRefTypeHint) -> bool {
 		matches!(self.as_string(type_hint), Some((_, StrType::CharPtr(_))))
 	}
@@ -299,6 +307,15 @@ impl<'tu, 'ge> TypeRefKind<'tu, 'ge> {
 		matches!(self.as_primitive(), Some((_, "void")))
 	}
 
+	/// True for opaque system handle types like `FILE` that are only usable through a pointer, see `OPAQUE_HANDLE_TYPES`
+	pub fn is_opaque_handle(&self) -> bool {
+		matches!(self.as_primitive(), Some(("c_void", cpp)) if settings::OPAQUE_HANDLE_TYPES.contains(cpp))
+	}
+
+	pub fn is_opaque_handle_ptr(&self) -> bool {
+		self.as_pointer().map_or(false, |inner| inner.kind().is_opaque_handle())
+	}
+
 	pub fn is_void_ptr(&self) -> bool {
 		self.as_pointer().map_or(false, |inner| inner.kind().is_void())
 	}
@@ -400,6 +417,7 @@ impl<'tu, 'ge> TypeRefKind<'tu, 'ge> {
 			let inner_kind = inner.kind();
 			// todo: support receiving slices for CUDA_RawVideoSourceTrait::get_next_packet
 			inner_kind.is_void()
+				|| inner_kind.is_opaque_handle()
 				|| inner_kind.is_unsigned_char()
 				|| inner_kind.as_pointer().map_or(false, |inner| inner.kind().is_copy(type_hint))
 				|| matches!(type_hint, TypeRefTypeHint::PrimitivePtrAsRaw)
@@ -1,6 +1,17 @@
+use std::rc::Rc;
+
+use clang::{Clang, EntityKind, Index, Unsaved};
+
 use crate::class::ClassDesc;
-use crate::type_ref::{TypeRef, TypeRefDesc};
-use crate::Class;
+use crate::field::{Field, FieldDesc};
+use crate::func::{FuncCppBody, FuncDesc, FuncKind, FuncRustBody, ReturnKind, Safety};
+use crate::test::clang_lock;
+use crate::type_ref::{Constness, CppNameStyle, ExternDir, NameStyle, TemplateArg, TypeRef, TypeRefDesc, TypeRefKind};
+use crate::typedef::NewTypedefResult;
+use crate::vector::VectorDesc;
+use crate::writer::rust_native::element::{RustElement, RustNativeGeneratedElement};
+use crate::writer::rust_native::type_ref::TypeRefExt;
+use crate::{settings, Class, Element, EntityExt, Func, GeneratorEnv, Typedef, Vector, WalkAction};
 
 #[test]
 fn test_map_vector() {
@@ -39,3 +50,207 @@ fn test_guess() {
 		unknown
 	);
 }
+
+#[test]
+fn test_opaque_handle_ptr() {
+	let file = TypeRef::guess("FILE", "core");
+	assert_eq!(TypeRefDesc::try_opaque_handle("FILE").expect("Opaque handle type"), file);
+	assert!(file.exclude_kind().is_ignored());
+
+	let file_ptr = TypeRef::new_pointer(file);
+	assert!(file_ptr.kind().is_opaque_handle_ptr());
+	assert!(file_ptr.exclude_kind().is_included());
+	assert_eq!("FILE*", file_ptr.cpp_name(CppNameStyle::Reference));
+	assert_eq!("*mut c_void", file_ptr.rust_name(NameStyle::ref_()));
+	assert_eq!("*mut c_void", file_ptr.rust_extern(ExternDir::ToCpp));
+
+	let func = Func::new_desc(FuncDesc::new(
+		FuncKind::Function,
+		Constness::Mut,
+		ReturnKind::Fallible,
+		"cv::dumpToFile",
+		"core",
+		vec![Field::new_desc(FieldDesc::new("f", file_ptr))],
+		FuncCppBody::Auto,
+		FuncRustBody::Auto,
+		TypeRefDesc::void(),
+	));
+	assert!(func.exclude_kind().is_included());
+	assert_eq!(Safety::Unsafe, func.safety());
+}
+
+#[test]
+fn test_vec_const_generics() {
+	// the same shape that clang produces for the `cv::Vec<T, N>` specializations
+	fn cv_vec_of<'tu, 'ge>(elem_type: TypeRef<'tu, 'ge>, len: usize) -> TypeRef<'tu, 'ge> {
+		TypeRef::new_desc(TypeRefDesc {
+			template_specialization_args: Rc::new([TemplateArg::Typename(elem_type), TemplateArg::Constant(len.to_string())]),
+			..TypeRefDesc::new(
+				TypeRefKind::Class(Class::new_desc(ClassDesc::simple("cv::Vec", "core"))),
+				Constness::Mut,
+			)
+		})
+	}
+
+	let vec3f = cv_vec_of(TypeRefDesc::float(), 3);
+	assert_eq!("cv::Vec<float, 3>", vec3f.cpp_name(CppNameStyle::Reference));
+	assert_eq!("core::VecN<f32, 3>", vec3f.rust_name(NameStyle::ref_()));
+	assert_eq!("core::VecN::<f32, 3>", vec3f.rust_name(NameStyle::ref_fish()));
+
+	let vec6d = cv_vec_of(TypeRefDesc::double(), 6);
+	assert_eq!("core::VecN<f64, 6>", vec6d.rust_name(NameStyle::ref_()));
+
+	// the typedefs like `cv::Vec3f` go through the same mapping when parsed from the headers
+	const HEADER: &str = "
+namespace cv {
+	template<typename _Tp, int cn> class Vec {
+	public:
+		_Tp val[cn];
+	};
+
+	typedef Vec<float, 3> Vec3f;
+}
+";
+	let _clang_lock = clang_lock();
+	let clang = Clang::new().expect("Can't initialize clang");
+	let index = Index::new(&clang, false, false);
+	let tu = index
+		.parser("vec.hpp")
+		.unsaved(&[Unsaved::new("vec.hpp", HEADER)])
+		.arguments(&["-xc++", "-std=c++14"])
+		.parse()
+		.expect("Can't parse header");
+	let root = tu.get_entity();
+	let gen_env = GeneratorEnv::new(root, "core");
+
+	let mut typedef = None;
+	root.walk_children_while(|ns| {
+		ns.walk_children_while(|child| {
+			if child.get_kind() == EntityKind::TypedefDecl {
+				typedef = Some(child);
+				WalkAction::Interrupt
+			} else {
+				WalkAction::Continue
+			}
+		});
+		WalkAction::Interrupt
+	});
+	let NewTypedefResult::Typedef(vec3f) = Typedef::try_new(typedef.expect("Can't find typedef"), &gen_env) else {
+		panic!("cv::Vec3f must stay a typedef");
+	};
+	assert_eq!("cv::Vec3f", vec3f.cpp_name(CppNameStyle::Reference));
+	assert_eq!("core::Vec3f", vec3f.rust_name(NameStyle::ref_()));
+	let underlying = vec3f.underlying_type_ref();
+	assert_eq!("cv::Vec<float, 3>", underlying.cpp_name(CppNameStyle::Reference));
+	assert_eq!("core::VecN<f32, 3>", underlying.rust_name(NameStyle::ref_()));
+	assert!(vec3f.gen_rust("4.9.0").contains("pub type Vec3f = core::VecN<f32, 3>;"));
+}
+
+#[test]
+fn test_cv_string_std_string() {
+	fn with_char_arg<'tu, 'ge>(string: TypeRef<'tu, 'ge>) -> TypeRef<'tu, 'ge> {
+		let kind = string.kind().into_owned();
+		TypeRef::new_desc(TypeRefDesc {
+			template_specialization_args: Rc::new([TemplateArg::Typename(TypeRefDesc::char())]),
+			..TypeRefDesc::new(kind, Constness::Const)
+		})
+	}
+
+	let cv_string = with_char_arg(TypeRefDesc::cv_string());
+	let std_string = with_char_arg(TypeRefDesc::std_string());
+	assert!(matches!(cv_string.kind().as_ref(), TypeRefKind::Class(_)));
+	assert!(cv_string.kind().is_std_or_cv_string(cv_string.type_hint()));
+	assert!(std_string.kind().is_std_or_cv_string(std_string.type_hint()));
+
+	assert_eq!("const cv::String", cv_string.cpp_name(CppNameStyle::Reference));
+	assert_eq!("const std::string", std_string.cpp_name(CppNameStyle::Reference));
+	assert_eq!(
+		cv_string.rust_name(NameStyle::ref_()),
+		std_string.rust_name(NameStyle::ref_())
+	);
+	assert_eq!(
+		cv_string.rust_extern(ExternDir::ToCpp),
+		std_string.rust_extern(ExternDir::ToCpp)
+	);
+	assert_eq!(
+		cv_string.rust_extern(ExternDir::FromCpp),
+		std_string.rust_extern(ExternDir::FromCpp)
+	);
+	assert_eq!("String", std_string.rust_name(NameStyle::ref_()));
+}
+
+#[test]
+fn test_nesting_depth_guard() {
+	fn nested_vector(depth: usize) -> TypeRef<'static, 'static> {
+		(1..depth).fold(TypeRefDesc::int(), |inner, _| {
+			TypeRef::new_vector(Vector::new_desc(VectorDesc::new(inner)))
+		})
+	}
+
+	let shallow = nested_vector(3);
+	assert!(!shallow.exceeds_nesting_depth(3));
+	assert!(shallow.exceeds_nesting_depth(2));
+	assert_eq!("std::vector<std::vector<int>>", shallow.cpp_name(CppNameStyle::Reference));
+	assert!(shallow.exclude_kind().is_included());
+
+	let max_depth = *settings::MAX_TYPE_NESTING_DEPTH;
+	let max = nested_vector(max_depth);
+	assert!(!max.exceeds_nesting_depth(max_depth));
+	assert!(!max.cpp_name(CppNameStyle::Reference).contains("..."));
+	assert!(max.exclude_kind().is_included());
+
+	let deep = (0..1000).fold(TypeRefDesc::int(), |inner, _| TypeRef::new_pointer(inner));
+	assert!(deep.exceeds_nesting_depth(max_depth));
+	assert_eq!(
+		format!("...{}", "*".repeat(max_depth)),
+		deep.cpp_name(CppNameStyle::Reference)
+	);
+
+	let func = |arg_type, return_type| {
+		Func::new_desc(FuncDesc::new(
+			FuncKind::Function,
+			Constness::Mut,
+			ReturnKind::Fallible,
+			"cv::deep",
+			"core",
+			vec![Field::new_desc(FieldDesc::new("arg", arg_type))],
+			FuncCppBody::Auto,
+			FuncRustBody::Auto,
+			return_type,
+		))
+	};
+	let shallow_func = func(shallow.clone(), TypeRefDesc::void());
+	assert!(shallow_func.too_deeply_nested_type().is_none());
+	assert!(shallow_func.exclude_kind().is_included());
+	let deep_arg_func = func(deep.clone(), TypeRefDesc::void());
+	assert_eq!(Some(&deep), deep_arg_func.too_deeply_nested_type().as_ref());
+	assert!(deep_arg_func.exclude_kind().is_excluded());
+	let deep_return_func = func(shallow, deep.clone());
+	assert_eq!(Some(deep), deep_return_func.too_deeply_nested_type());
+	assert!(deep_return_func.exclude_kind().is_excluded());
+}
+
+#[test]
+fn test_std_function_ignored() {
+	let std_function = TypeRef::new_class(Class::new_desc(ClassDesc::system(
+		"std::function<void (const cv::Range &)>",
+		"core",
+	)));
+	assert!(std_function.exclude_kind().is_ignored());
+	let std_function_ref = TypeRef::new_reference(std_function.with_inherent_constness(Constness::Const));
+	assert!(std_function_ref.exclude_kind().is_ignored());
+	assert!(TypeRef::new_class(ClassDesc::cv_mat()).exclude_kind().is_included());
+
+	let func = Func::new_desc(FuncDesc::new(
+		FuncKind::Function,
+		Constness::Mut,
+		ReturnKind::Fallible,
+		"cv::parallel_for_",
+		"core",
+		vec![Field::new_desc(FieldDesc::new("functor", std_function_ref))],
+		FuncCppBody::Auto,
+		FuncRustBody::Auto,
+		TypeRefDesc::void(),
+	));
+	assert!(!func.exclude_kind().is_included());
+}
@@ -88,12 +88,12 @@ impl Element for Typedef<'_, '_> {
 		DefaultElement::exclude_kind(self)
 			.with_exclude_kind(|| self.underlying_type_ref().exclude_kind())
 			.with_is_excluded(|| {
-				settings::PRIMITIVE_TYPEDEFS.contains_key(self.cpp_name(CppNameStyle::Reference).as_ref()) || {
-					let underlying_type = self.underlying_type_ref();
+				let underlying_type = self.underlying_type_ref();
+				settings::PRIMITIVE_TYPEDEFS.contains_key(self.cpp_name(CppNameStyle::Reference).as_ref())
+					|| underlying_type.exceeds_nesting_depth(*settings::MAX_TYPE_NESTING_DEPTH)
 					// fixes recursive typedefs like Cv16suf or GKernelPackage
 					// fixme: don't rely on rust name to disconnect this generic module from rust_native
-					self.type_ref().rust_name(NameStyle::ref_()) == underlying_type.rust_name(NameStyle::ref_())
-				}
+					|| self.type_ref().rust_name(NameStyle::ref_()) == underlying_type.rust_name(NameStyle::ref_())
 			})
 	}
 
@@ -76,7 +76,6 @@ fn gen_rust_class(c: &Class, opencv_version: &str) -> String {
 				.collect(),
 		)
 	};
-	let mut field_const_methods = const_methods.clone();
 	let methods = c.methods();
 
 	// make some more room for companion funcs
@@ -196,19 +195,7 @@ fn gen_rust_class(c: &Class, opencv_version: &str) -> String {
 			]));
 		}
 		if !settings::IMPLEMENTED_MANUAL_DEBUG.contains(c.cpp_name(CppNameStyle::Reference).as_ref()) {
-			for b in &bases {
-				let base_fields = b.fields();
-				let base_field_const_methods = b.field_methods(
-					base_fields.iter().filter(|f| f.exclude_kind().is_included()),
-					Some(Constness::Const),
-				);
-				field_const_methods.extend(base_field_const_methods);
-			}
-			let debug_fields = rust_generate_debug_fields(field_const_methods);
-			impls += &IMPL_DEBUG_TPL.interpolate(&HashMap::from([
-				("rust_local", rust_local.as_ref()),
-				("debug_fields", &debug_fields),
-			]));
+			impls += &IMPL_DEBUG_TPL.interpolate(&HashMap::from([("rust_local", rust_local.as_ref())]));
 		}
 	}
 
@@ -363,22 +350,6 @@ where
 	.join("")
 }
 
-pub fn rust_generate_debug_fields(field_const_methods: Vec<Func>) -> String {
-	field_const_methods
-		.into_iter()
-		.filter(|f| f.exclude_kind().is_included() && f.return_type_ref().kind().is_debug())
-		.filter_map(|f| {
-			f.kind().as_field_accessor().map(|(cls, _)| {
-				format!(
-					"\n\t.field(\"{name}\", &{trait_name}::{name}(self))",
-					trait_name = cls.rust_trait_name(NameStyle::ref_fish(), Constness::Const),
-					name = f.rust_leafname(FishStyle::No)
-				)
-			})
-		})
-		.join("")
-}
-
 impl RustElement for Class<'_, '_> {
 	fn rust_module(&self) -> Cow<str> {
 		match self {
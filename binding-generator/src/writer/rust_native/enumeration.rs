@@ -5,7 +5,7 @@ use once_cell::sync::Lazy;
 
 use crate::debug::NameDebug;
 use crate::type_ref::{FishStyle, NameStyle};
-use crate::{CompiledInterpolation, CppNameStyle, Element, EntityElement, Enum, StrExt};
+use crate::{settings, CompiledInterpolation, CppNameStyle, Element, EntityElement, Enum, StrExt};
 
 use super::element::{DefaultRustNativeElement, RustElement};
 use super::RustNativeGeneratedElement;
@@ -28,14 +28,14 @@ impl RustElement for Enum<'_> {
 	}
 }
 
+static ENUM_TPL: Lazy<CompiledInterpolation> = Lazy::new(|| include_str!("tpl/enum/enum.tpl.rs").compile_interpolation());
+
 impl RustNativeGeneratedElement for Enum<'_> {
 	fn element_safe_id(&self) -> String {
 		format!("{}-{}", self.rust_module(), self.rust_name(NameStyle::decl()))
 	}
 
 	fn gen_rust(&self, opencv_version: &str) -> String {
-		static ENUM_TPL: Lazy<CompiledInterpolation> = Lazy::new(|| include_str!("tpl/enum/enum.tpl.rs").compile_interpolation());
-
 		static CONST_TPL: Lazy<CompiledInterpolation> = Lazy::new(|| include_str!("tpl/enum/const.tpl.rs").compile_interpolation());
 
 		static CONST_IGNORED_TPL: Lazy<CompiledInterpolation> =
@@ -51,6 +51,7 @@ impl RustNativeGeneratedElement for Enum<'_> {
 
 		let mut enum_consts = Vec::with_capacity(consts.len());
 		let mut from_consts = Vec::with_capacity(consts.len());
+		let mut all_variants = Vec::with_capacity(consts.len());
 
 		let mut generated_values = HashMap::<String, String>::with_capacity(consts.len());
 		for c in consts {
@@ -77,17 +78,132 @@ impl RustNativeGeneratedElement for Enum<'_> {
 			]);
 			enum_consts.push(enum_const_tpl.interpolate(&inter_vars));
 			from_consts.push(from_const_tpl.interpolate(&inter_vars));
+			if duplicate_name.is_none() {
+				all_variants.push(format!("Self::{name}"));
+			}
 
 			generated_values.insert(value, name);
 		}
 
+		let int_type = self.rust_repr();
+		let repr = if int_type == "i32" {
+			"C"
+		} else {
+			int_type
+		};
+
+		let from_i32_value = rust_from_i32_value(int_type);
+
+		let cpp_refname = self.cpp_name(CppNameStyle::Reference);
+		let non_exhaustive = rust_non_exhaustive_attr(settings::NON_EXHAUSTIVE_ENUMS.contains(cpp_refname.as_ref()));
+
+		let rust_local = self.rust_name(NameStyle::decl());
+		let rust_full = self.rust_name(NameStyle::ref_());
+		let flags = if settings::FLAGS_ENUMS.contains_key(cpp_refname.as_ref()) {
+			rust_flags(&rust_local, &rust_full, int_type)
+		} else {
+			String::new()
+		};
+
 		ENUM_TPL.interpolate(&HashMap::from([
-			("rust_local", self.rust_name(NameStyle::decl()).as_ref()),
-			("rust_full", self.rust_name(NameStyle::ref_()).as_ref()),
+			("repr", repr),
+			("non_exhaustive", non_exhaustive),
+			("int_type", int_type),
+			("rust_local", rust_local.as_ref()),
+			("rust_full", rust_full.as_ref()),
 			("doc_comment", &self.rendered_doc_comment("///", opencv_version)),
 			("debug", &self.get_debug()),
 			("enum_consts", &enum_consts.join("")),
 			("from_consts", &from_consts.join("")),
+			("all_variants", &all_variants.join(", ")),
+			("from_i32_value", &from_i32_value),
+			("flags", &flags),
+		]))
+	}
+}
+
+/// Conversion of the `value: i32` argument of `from_i32()` to the `int_type` accepted by `TryFrom`
+fn rust_from_i32_value(int_type: &str) -> Cow<'static, str> {
+	match int_type {
+		"i32" => "value".into(),
+		"i64" => "i64::from(value)".into(),
+		_ => format!("{int_type}::try_from(value).ok()?").into(),
+	}
+}
+
+/// `{rust_local}Flags` struct for the enums from `FLAGS_ENUMS`
+fn rust_flags(rust_local: &str, rust_full: &str, int_type: &str) -> String {
+	static TPL: Lazy<CompiledInterpolation> = Lazy::new(|| include_str!("tpl/enum/flags.tpl.rs").compile_interpolation());
+
+	TPL.interpolate(&HashMap::from([
+		("rust_local", rust_local),
+		("rust_full", rust_full),
+		("int_type", int_type),
+	]))
+}
+
+fn rust_non_exhaustive_attr(non_exhaustive: bool) -> &'static str {
+	if non_exhaustive {
+		"#[non_exhaustive]"
+	} else {
+		""
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use std::collections::HashMap;
+
+	use super::{rust_flags, rust_from_i32_value, rust_non_exhaustive_attr, ENUM_TPL};
+
+	fn render(non_exhaustive: bool, flags: &str) -> String {
+		ENUM_TPL.interpolate(&HashMap::from([
+			("repr", "C"),
+			("non_exhaustive", rust_non_exhaustive_attr(non_exhaustive)),
+			("int_type", "i32"),
+			("rust_local", "BorderTypes"),
+			("rust_full", "core::BorderTypes"),
+			("doc_comment", ""),
+			("debug", ""),
+			("enum_consts", "BORDER_CONSTANT = 0,\n"),
+			("from_consts", "0 => Ok(Self::BORDER_CONSTANT),\n"),
+			("all_variants", "Self::BORDER_CONSTANT"),
+			("from_i32_value", "value"),
+			("flags", flags),
 		]))
 	}
+
+	#[test]
+	fn non_exhaustive() {
+		let enabled = render(true, "");
+		assert!(enabled.contains("#[non_exhaustive]\npub enum BorderTypes {"), "{enabled}");
+		let disabled = render(false, "");
+		assert!(!disabled.contains("non_exhaustive"), "{disabled}");
+		assert!(disabled.contains("pub enum BorderTypes {"), "{disabled}");
+	}
+
+	#[test]
+	fn flags() {
+		let flags = rust_flags("ImreadModes", "crate::imgcodecs::ImreadModes", "i32");
+		let rendered = render(false, &flags);
+		assert!(rendered.contains("pub struct ImreadModesFlags(pub i32);"), "{rendered}");
+		assert!(
+			rendered.contains("opencv_type_enum_flags! { ImreadModesFlags, crate::imgcodecs::ImreadModes, i32 }"),
+			"{rendered}"
+		);
+		let rendered = render(false, "");
+		assert!(!rendered.contains("Flags"), "{rendered}");
+	}
+
+	#[test]
+	fn from_i32() {
+		let rendered = render(false, "");
+		assert!(
+			rendered.contains("pub fn from_i32(value: i32) -> Option<Self> {\n\t\tSelf::try_from(value).ok()\n\t}"),
+			"{rendered}"
+		);
+		assert_eq!("value", rust_from_i32_value("i32"));
+		assert_eq!("i64::from(value)", rust_from_i32_value("i64"));
+		assert_eq!("u64::try_from(value).ok()?", rust_from_i32_value("u64"));
+	}
 }
@@ -1,16 +1,20 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt::Write;
+use std::iter;
 use std::rc::Rc;
 use Cow::{Borrowed, Owned};
 
 use once_cell::sync::Lazy;
 
+use crate::class::ClassDesc;
 use crate::field::Field;
 use crate::func::{FuncCppBody, FuncKind, FuncRustBody, FuncRustExtern, InheritConfig, OperatorKind, ReturnKind, Safety};
 use crate::name_pool::NamePool;
 use crate::settings::ARG_OVERRIDE_SELF;
-use crate::type_ref::{Constness, CppNameStyle, ExternDir, FishStyle, NameStyle, StrEnc, StrType, TypeRef, TypeRefTypeHint};
+use crate::type_ref::{
+	Constness, CppNameStyle, ExternDir, FishStyle, InputOutputArrayKind, NameStyle, StrEnc, StrType, TypeRef, TypeRefTypeHint,
+};
 use crate::{reserved_rename, settings, CompiledInterpolation, Element, Func, IteratorExt, NameDebug, StrExt, StringExt};
 
 use super::comment::{render_ref, RenderComment};
@@ -170,7 +174,10 @@ impl RustElement for Func<'_, '_> {
 				cpp_name
 			}
 		};
-		if let Some(&name) = settings::FUNC_RENAME.get(self.identifier().as_str()) {
+		if let Some(&name) = settings::FUNC_RENAME
+			.get(self.identifier().as_str())
+			.or_else(|| settings::FUNC_CLOSURE_ARG.get(&self.func_id()))
+		{
 			if name.contains('+') {
 				reserved_rename(name.replace('+', rust_name.as_ref()).cpp_name_to_rust_case().into())
 			} else {
@@ -227,8 +234,28 @@ impl RustNativeGeneratedElement for Func<'_, '_> {
 				.filter(|(_, boxed_arg_name, _)| *boxed_arg_name == ARG_OVERRIDE_SELF)
 				.map_or(Lifetime::Elided, |(_, _, lt)| lt);
 			decl_args.push(render_lane.rust_self_func_decl(lt));
-			call_args.push(render_lane.rust_arg_func_call("self"));
+			let self_call = render_lane.rust_arg_func_call("self");
+			if !return_kind.is_infallible()
+				&& cls.kind().is_boxed()
+				&& settings::NULL_RECEIVER_CHECK.contains(cls.cpp_name(CppNameStyle::Reference).as_ref())
+			{
+				pre_call_args.push(rust_null_receiver_check(&self_call, &cls.cpp_name(CppNameStyle::Reference)));
+			}
+			call_args.push(self_call);
 		}
+		let tuple_return = settings::FUNC_TUPLE_RETURN.contains(&self.func_id());
+		let mut tuple_decl_args = decl_args.clone();
+		let mut tuple_forward_args = Vec::with_capacity(args.len());
+		let mut tuple_outputs = Vec::with_capacity(args.len());
+		let out_return = settings::FUNC_OUT_RETURN.contains(&self.func_id());
+		let mut out_decl_args = decl_args.clone();
+		let mut out_forward_args = Vec::with_capacity(args.len());
+		let mut out_outputs = Vec::with_capacity(1);
+		let builder_overrides = settings::FUNC_BUILDER.get(&self.func_id());
+		let mut builder_decl_args = Vec::with_capacity(args.len());
+		let mut builder_forward_args = Vec::with_capacity(args.len());
+		let mut builder_fields = Vec::with_capacity(args.len());
+		let mut builder_fields_by_value = true;
 		let mut callback_arg_name: Option<&str> = None;
 		for (name, arg) in &args {
 			let arg_type_ref = arg.type_ref();
@@ -251,7 +278,45 @@ impl RustNativeGeneratedElement for Func<'_, '_> {
 					let lt = boxed_ref_arg
 						.filter(|(_, boxed_arg_name, _)| *boxed_arg_name == name)
 						.map_or(Lifetime::Elided, |(_, _, lt)| lt);
-					decl_args.push(render_lane.rust_arg_func_decl(name, lt).into());
+					let flags_type = rust_flags_arg_type(self, arg);
+					let decl_arg = if let Some(flags_type) = &flags_type {
+						pre_post_arg_handle(rust_flags_arg_pre_call(name, flags_type), &mut pre_call_args);
+						rust_flags_arg_decl(name, flags_type)
+					} else {
+						render_lane.rust_arg_func_decl(name, lt)
+					};
+					if tuple_return {
+						if matches!(arg_kind.input_output_array_kind(), Some(InputOutputArrayKind::Output)) {
+							tuple_outputs.push(name.as_str());
+							tuple_forward_args.push(format!("&mut {name}"));
+						} else {
+							tuple_decl_args.push(decl_arg.clone().into());
+							tuple_forward_args.push(name.clone());
+						}
+					}
+					if out_return {
+						if let Some(output_type) = rust_out_return_output_type(arg) {
+							out_outputs.push((name.as_str(), output_type));
+							out_forward_args.push(format!("&mut {name}"));
+						} else {
+							out_decl_args.push(decl_arg.clone().into());
+							out_forward_args.push(name.clone());
+						}
+					}
+					if let Some(builder_overrides) = builder_overrides {
+						if let Some(default) = rust_builder_default(arg, builder_overrides) {
+							// the builder struct is `Copy`, so are its fields
+							builder_fields_by_value &= flags_type.is_none()
+								&& arg_kind.is_copy(arg_type_ref.type_hint())
+								&& !arg_kind.is_char_ptr_string(arg_type_ref.type_hint());
+							builder_fields.push((name.as_str(), decl_arg.clone(), default));
+							builder_forward_args.push(format!("self.{name}"));
+						} else {
+							builder_decl_args.push(decl_arg.clone());
+							builder_forward_args.push(name.clone());
+						}
+					}
+					decl_args.push(decl_arg.into());
 				}
 				pre_post_arg_handle(
 					render_lane.rust_arg_pre_call(name, return_kind.is_infallible()),
@@ -326,7 +391,7 @@ impl RustNativeGeneratedElement for Func<'_, '_> {
 			}
 		}
 
-		TPL.interpolate(&HashMap::from([
+		let mut out = TPL.interpolate(&HashMap::from([
 			("doc_comment", doc_comment.as_str()),
 			("debug", &self.get_debug()),
 			("attributes", &attributes.join("\n")),
@@ -345,7 +410,74 @@ impl RustNativeGeneratedElement for Func<'_, '_> {
 			("return_handle", &ret_handle),
 			("post_success_call_args", &post_success_call_args.join("\n")),
 			("return", ret_stmt),
-		]))
+		]));
+		if tuple_return && !tuple_outputs.is_empty() && safety.is_safe() && !return_kind.is_infallible() && boxed_ref_arg.is_none()
+		{
+			let return_type = return_type_ref.rust_return(FishStyle::No, return_lifetime);
+			out.push('\n');
+			out.push_str(&rust_tuple_return(
+				self,
+				&name,
+				visibility,
+				&attributes.join("\n"),
+				&rust_generic_decl(self, &return_type_ref),
+				&tuple_decl_args,
+				&tuple_forward_args,
+				&tuple_outputs,
+				(return_type != "()").then_some(return_type.as_ref()),
+			));
+		}
+		if let [(output, output_type)] = out_outputs.as_slice() {
+			if return_type_ref.kind().is_void() && safety.is_safe() && !return_kind.is_infallible() {
+				out.push('\n');
+				out.push_str(&rust_out_return(
+					self,
+					&name,
+					visibility,
+					&attributes.join("\n"),
+					&rust_generic_decl(self, &return_type_ref),
+					&out_decl_args,
+					&out_forward_args,
+					output,
+					output_type,
+				));
+			}
+		}
+		if !builder_fields.is_empty() && builder_fields_by_value && safety.is_safe() && matches!(kind.as_ref(), FuncKind::Function)
+		{
+			out.push('\n');
+			out.push_str(&rust_builder(
+				self,
+				&name,
+				&attributes.join("\n"),
+				&rust_generic_decl(self, &return_type_ref),
+				&builder_decl_args,
+				&builder_forward_args,
+				&builder_fields,
+				&return_type_func_decl,
+			));
+		}
+		if let FuncKind::FunctionOperator(operator) = kind.as_ref() {
+			if settings::FUNC_OPERATOR_TRAIT.contains(&self.func_id()) && safety.is_safe() && boxed_ref_arg.is_none() {
+				let cfg_attr = settings::FUNC_CFG_ATTR
+					.get(identifier.as_str())
+					.filter(|(rust_attr, _)| !rust_attr.is_empty())
+					.map(|(rust_attr, _)| format!("#[cfg({rust_attr})]"));
+				let return_type = return_type_ref.rust_return(FishStyle::No, return_lifetime);
+				let output = if return_kind.is_infallible() {
+					return_type.into_owned()
+				} else {
+					format!("Result<{return_type}>")
+				};
+				if let Some(operator_impl) =
+					rust_operator_trait(self, *operator, &name, cfg_attr.as_deref().unwrap_or(""), &args, &output)
+				{
+					out.push('\n');
+					out.push_str(&operator_impl);
+				}
+			}
+		}
+		out
 	}
 
 	fn gen_rust_externs(&self) -> String {
@@ -517,6 +649,72 @@ fn pre_post_arg_handle(mut arg: String, args: &mut Vec<String>) {
 	}
 }
 
+/// Rust path of the flags struct for the `int` flags argument `arg` of the function `f`, see `FLAGS_ENUMS`
+fn rust_flags_arg_type(f: &Func, arg: &Field) -> Option<String> {
+	let name = arg.cpp_name(CppNameStyle::Declaration);
+	if name != "flags" && !name.ends_with("Flags") {
+		return None;
+	}
+	if !matches!(arg.type_ref().kind().as_primitive(), Some((_, "int"))) {
+		return None;
+	}
+	let doc_comment = f.doc_comment_overloaded();
+	let param_doc = doc_comment_param(&doc_comment, &name)?;
+	let mut flags_enums = settings::FLAGS_ENUMS
+		.iter()
+		.filter(|(enum_cpp_refname, _)| mentions_name(&param_doc, enum_cpp_refname));
+	match (flags_enums.next(), flags_enums.next()) {
+		(Some((enum_cpp_refname, rust_module)), None) => {
+			Some(format!("crate::{rust_module}::{}Flags", enum_cpp_refname.localname()))
+		}
+		_ => None,
+	}
+}
+
+/// Text of the `@param` documentation of the argument `arg_name`, including its continuation lines
+fn doc_comment_param(doc_comment: &str, arg_name: &str) -> Option<String> {
+	let mut lines = doc_comment.lines().map(str::trim);
+	let first_line = lines.find_map(|line| {
+		let param = line
+			.strip_prefix("@param")
+			.or_else(|| line.strip_prefix("\\param"))?
+			.trim_start();
+		let param = ["[in]", "[out]", "[in,out]"]
+			.iter()
+			.find_map(|direction| param.strip_prefix(direction))
+			.unwrap_or(param);
+		let desc = param.trim_start().strip_prefix(arg_name)?;
+		(desc.is_empty() || desc.starts_with(char::is_whitespace)).then(|| desc.trim_start())
+	})?;
+	let continuation = lines.take_while(|line| !line.is_empty() && !line.starts_with(['@', '\\']));
+	Some(iter::once(first_line).chain(continuation).join(" "))
+}
+
+/// Returns true if `text` contains the C++ name `cpp_refname` that's not a part of a longer identifier
+fn mentions_name(text: &str, cpp_refname: &str) -> bool {
+	text.match_indices(cpp_refname).any(|(idx, _)| {
+		let is_ident_char = |c: char| c.is_alphanumeric() || c == '_';
+		!text[..idx].ends_with(is_ident_char) && !text[idx + cpp_refname.len()..].starts_with(is_ident_char)
+	})
+}
+
+/// Declaration of the flags argument, it accepts the flags struct, a single enum value or a raw `i32`
+fn rust_flags_arg_decl(name: &str, flags_type: &str) -> String {
+	format!("{name}: impl Into<{flags_type}>")
+}
+
+/// Conversion of the flags argument to the raw value that's passed to OpenCV
+fn rust_flags_arg_pre_call(name: &str, flags_type: &str) -> String {
+	format!("let {name} = {flags_type}::bits({name}.into())")
+}
+
+/// Early return with an error if the `self_call` (e.g. `self.as_raw_Mat()`) returns a null pointer
+fn rust_null_receiver_check(self_call: &str, cls_cpp_name: &str) -> String {
+	format!(
+		"if {self_call}.is_null() {{ return Err(crate::Error::new(crate::core::StsNullPtr, \"Null {cls_cpp_name} receiver\")); }}"
+	)
+}
+
 fn rust_call(
 	f: &Func,
 	func_safety: Safety,
@@ -549,6 +747,293 @@ fn rust_call(
 	]))
 }
 
+/// Companion for the functions from `FUNC_TUPLE_RETURN` that returns the output arguments as a tuple
+fn rust_tuple_return(
+	f: &Func,
+	func_name: &str,
+	visibility: &str,
+	attributes: &str,
+	generic_decl: &str,
+	decl_args: &[Cow<str>],
+	forward_args: &[String],
+	outputs: &[&str],
+	return_type: Option<&str>,
+) -> String {
+	#![allow(clippy::too_many_arguments)]
+	static TPL: Lazy<CompiledInterpolation> = Lazy::new(|| include_str!("tpl/func/rust_tuple.tpl.rs").compile_interpolation());
+
+	let output_type_ref = TypeRef::new_class(ClassDesc::cv_mat());
+	let output_type = output_type_ref.rust_name(NameStyle::ref_());
+	let mut rv_rust = Vec::with_capacity(outputs.len() + 1);
+	let mut ret = Vec::with_capacity(outputs.len() + 1);
+	if let Some(return_type) = return_type {
+		rv_rust.push(return_type);
+		ret.push("ret");
+	}
+	rv_rust.extend(outputs.iter().map(|_| output_type.as_ref()));
+	ret.extend(outputs);
+	let pre_call_outputs = outputs
+		.iter()
+		.map(|name| format!("let mut {name} = {output_type}::default();"))
+		.join("\n");
+	let call_prefix = match f.kind().as_ref() {
+		FuncKind::StaticMethod(_) => "Self::",
+		kind if kind.as_instance_method().is_some() => "self.",
+		_ => "",
+	};
+	let ret_receive = if return_type.is_some() {
+		"let ret = "
+	} else {
+		""
+	};
+	TPL.interpolate(&HashMap::from([
+		("refr", render_ref(f, Some(func_name)).as_ref()),
+		("attributes", attributes),
+		("visibility", visibility),
+		("name", func_name),
+		("generic_decl", generic_decl),
+		("decl_args", &decl_args.join(", ")),
+		("rv_rust", &format!("({})", rv_rust.join(", "))),
+		("pre_call_outputs", &pre_call_outputs),
+		("ret_receive", ret_receive),
+		("call_prefix", call_prefix),
+		("forward_args", &forward_args.join(", ")),
+		("ret", &format!("({})", ret.join(", "))),
+	]))
+}
+
+/// Alternative version of the void functions from `FUNC_OUT_RETURN` that returns their single output argument
+fn rust_out_return(
+	f: &Func,
+	func_name: &str,
+	visibility: &str,
+	attributes: &str,
+	generic_decl: &str,
+	decl_args: &[Cow<str>],
+	forward_args: &[String],
+	output: &str,
+	output_type: &str,
+) -> String {
+	#![allow(clippy::too_many_arguments)]
+	static TPL: Lazy<CompiledInterpolation> =
+		Lazy::new(|| include_str!("tpl/func/rust_out_return.tpl.rs").compile_interpolation());
+
+	let call_prefix = match f.kind().as_ref() {
+		FuncKind::StaticMethod(_) => "Self::",
+		kind if kind.as_instance_method().is_some() => "self.",
+		_ => "",
+	};
+	TPL.interpolate(&HashMap::from([
+		("refr", render_ref(f, Some(func_name)).as_ref()),
+		("attributes", attributes),
+		("visibility", visibility),
+		("name", func_name),
+		("generic_decl", generic_decl),
+		("decl_args", &decl_args.join(", ")),
+		("output", output),
+		("output_type", output_type),
+		("call_prefix", call_prefix),
+		("forward_args", &forward_args.join(", ")),
+	]))
+}
+
+/// Type of the value returned by the `FUNC_OUT_RETURN` companion if `arg` is its output argument
+///
+/// Output arrays are returned as `Mat` and mutable references to primitives as the primitive itself. Other types are not
+/// outputs because the generated simple classes don't necessarily have a `Default` value to start with.
+fn rust_out_return_output_type(arg: &Field) -> Option<String> {
+	let type_ref = arg.type_ref();
+	let kind = type_ref.kind();
+	if matches!(kind.input_output_array_kind(), Some(InputOutputArrayKind::Output)) {
+		Some(
+			TypeRef::new_class(ClassDesc::cv_mat())
+				.rust_name(NameStyle::ref_())
+				.into_owned(),
+		)
+	} else {
+		kind
+			.as_reference()
+			.filter(|inner| inner.constness().is_mut() && inner.kind().as_primitive().is_some())
+			.map(|inner| inner.rust_name(NameStyle::ref_()).into_owned())
+	}
+}
+
+/// `std::ops` trait implementation for the operator functions from `FUNC_OPERATOR_TRAIT`
+///
+/// Returns `None` if the operator has no binary trait counterpart or if any of the arguments can't be used in the impl
+/// header.
+fn rust_operator_trait(
+	f: &Func,
+	operator: OperatorKind,
+	func_name: &str,
+	attributes: &str,
+	args: &[(String, Field)],
+	output: &str,
+) -> Option<String> {
+	static TPL: Lazy<CompiledInterpolation> =
+		Lazy::new(|| include_str!("tpl/func/rust_operator_trait.tpl.rs").compile_interpolation());
+
+	let (trait_name, trait_method) = match operator {
+		OperatorKind::Add => ("Add", "add"),
+		OperatorKind::Sub => ("Sub", "sub"),
+		OperatorKind::Mul => ("Mul", "mul"),
+		OperatorKind::Div => ("Div", "div"),
+		OperatorKind::And => ("BitAnd", "bitand"),
+		OperatorKind::Or => ("BitOr", "bitor"),
+		OperatorKind::Xor => ("BitXor", "bitxor"),
+		_ => return None,
+	};
+	let [(_, lhs), (_, rhs)] = args else {
+		return None;
+	};
+	let lhs_type = rust_operator_trait_arg_type(lhs)?;
+	let rhs_type = rust_operator_trait_arg_type(rhs)?;
+	Some(TPL.interpolate(&HashMap::from([
+		("refr", render_ref(f, Some(func_name)).as_ref()),
+		("attributes", attributes),
+		("trait_name", trait_name),
+		("trait_method", trait_method),
+		("lhs_type", &lhs_type),
+		("rhs_type", &rhs_type),
+		("output", output),
+		("name", func_name),
+	])))
+}
+
+/// Type of the operator argument for the trait impl header, a reference for the boxed classes and the type itself for the
+/// `Copy` types
+fn rust_operator_trait_arg_type(arg: &Field) -> Option<String> {
+	let type_ref = arg.type_ref();
+	let kind = type_ref.kind();
+	if let Some(inner) = kind.as_reference() {
+		let is_const_boxed = inner.constness().is_const() && inner.kind().as_class().map_or(false, |cls| cls.kind().is_boxed());
+		is_const_boxed.then(|| format!("&{}", inner.rust_name(NameStyle::ref_())))
+	} else if kind.is_copy(type_ref.type_hint()) {
+		Some(type_ref.rust_name(NameStyle::ref_()).into_owned())
+	} else {
+		None
+	}
+}
+
+/// Rust expression for the default value of the builder field, either the override from `FUNC_BUILDER` or the one derived from
+/// the C++ default value of the argument
+///
+/// Only the numeric and `bool` literals and the simple classes constructed from those (e.g. `Size(21,21)`) are derived,
+/// `None` is returned for the rest of the arguments, they are passed to the `call()` method.
+fn rust_builder_default(arg: &Field, overrides: &HashMap<&str, &str>) -> Option<String> {
+	if let Some(&default) = overrides.get(arg.cpp_name(CppNameStyle::Declaration).as_ref()) {
+		return Some(default.to_string());
+	}
+	let default = arg.default_value()?;
+	let type_ref = arg.type_ref();
+	let kind = type_ref.kind();
+	if let Some((rust_type, _)) = kind.as_primitive() {
+		rust_literal(&default, rust_type)
+	} else if kind.as_class().map_or(false, |cls| cls.kind().is_simple()) {
+		let (cls_name, args) = default.strip_suffix(')')?.split_once('(')?;
+		let type_name = type_ref.cpp_name(CppNameStyle::Declaration);
+		if cls_name.localname() != type_name.trim_start_matches("const ") {
+			return None;
+		}
+		let rust_name = type_ref.rust_name(NameStyle::ref_());
+		if args.is_empty() {
+			return Some(format!("{rust_name}::default()"));
+		}
+		// the element type of the template classes like `Size_<float>`, the literals are used as is for the rest
+		let source = type_ref.source();
+		let elem_type = source
+			.template_specialization_args()
+			.iter()
+			.find_map(|arg| arg.as_typename().and_then(|typ| typ.kind().as_primitive()));
+		let args = args
+			.split(',')
+			.map(|arg| {
+				if let Some((rust_type, _)) = elem_type {
+					rust_literal(arg, rust_type)
+				} else {
+					rust_literal(arg, "i64").or_else(|| rust_literal(arg, "f64"))
+				}
+			})
+			.collect::<Option<Vec<_>>>()?;
+		Some(format!("{rust_name}::new({args})", args = args.join(", ")))
+	} else {
+		None
+	}
+}
+
+/// Rust literal of the `rust_type` primitive for the C++ numeric or `bool` literal, e.g. `1.f` -> `1.`
+fn rust_literal(cpp_literal: &str, rust_type: &str) -> Option<String> {
+	match rust_type {
+		"bool" => matches!(cpp_literal, "true" | "false").then(|| cpp_literal.to_string()),
+		"f32" | "f64" => {
+			let literal = cpp_literal.trim_end_matches(['f', 'F']);
+			literal.parse::<f64>().ok()?;
+			if literal.contains(['.', 'e', 'E']) {
+				Some(literal.to_string())
+			} else {
+				Some(format!("{literal}."))
+			}
+		}
+		_ => {
+			let literal = cpp_literal.trim_end_matches(['u', 'U', 'l', 'L']);
+			literal.parse::<i64>().ok()?;
+			Some(literal.to_string())
+		}
+	}
+}
+
+/// Builder struct for the functions from `FUNC_BUILDER` that allows setting the optional arguments one by one
+fn rust_builder(
+	f: &Func,
+	func_name: &str,
+	attributes: &str,
+	generic_decl: &str,
+	decl_args: &[String],
+	forward_args: &[String],
+	fields: &[(&str, String, String)],
+	return_type_func_decl: &str,
+) -> String {
+	#![allow(clippy::too_many_arguments)]
+	static TPL: Lazy<CompiledInterpolation> = Lazy::new(|| include_str!("tpl/func/rust_builder.tpl.rs").compile_interpolation());
+
+	let struct_name = format!(
+		"{}Builder",
+		func_name
+			.split('_')
+			.filter(|part| !part.is_empty())
+			.map(|part| {
+				let mut part = part.to_string();
+				part[..1].make_ascii_uppercase();
+				part
+			})
+			.join("")
+	);
+	let struct_fields = fields.iter().map(|(_, decl, _)| format!("{decl},")).join("\n");
+	let defaults = fields
+		.iter()
+		.map(|(name, _, default)| format!("{name}: {default},"))
+		.join("\n");
+	let setters = fields
+		.iter()
+		.map(|(name, decl, _)| {
+			format!("#[inline]\npub fn with_{name}(mut self, {decl}) -> Self {{\n\tself.{name} = {name};\n\tself\n}}")
+		})
+		.join("\n\n");
+	TPL.interpolate(&HashMap::from([
+		("refr", render_ref(f, Some(func_name)).as_ref()),
+		("attributes", attributes),
+		("struct_name", &struct_name),
+		("fields", &struct_fields),
+		("defaults", &defaults),
+		("setters", &setters),
+		("name", func_name),
+		("generic_decl", generic_decl),
+		("decl_args", &decl_args.join(", ")),
+		("rv_rust_full", return_type_func_decl),
+		("forward_args", &forward_args.join(", ")),
+	]))
+}
+
 fn rust_return(
 	f: &Func,
 	return_type_ref: &TypeRef,
@@ -947,3 +1432,313 @@ fn companion_func_boxref_mut<'tu, 'ge>(f: &Func<'tu, 'ge>) -> Option<Func<'tu, '
 		None
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use crate::class::ClassDesc;
+	use crate::field::{Field, FieldDesc};
+	use crate::func::{FuncCppBody, FuncDesc, FuncKind, FuncRustBody, OperatorKind, ReturnKind};
+	use crate::type_ref::{Constness, TypeRef, TypeRefDesc};
+	use crate::writer::rust_native::RustNativeGeneratedElement;
+	use crate::{Class, Func};
+
+	use super::{
+		doc_comment_param, mentions_name, rust_flags_arg_decl, rust_flags_arg_pre_call, rust_null_receiver_check,
+		rust_out_return_output_type,
+	};
+
+	#[test]
+	fn tuple_return() {
+		let func = Func::new_desc(FuncDesc::new(
+			FuncKind::Function,
+			Constness::Mut,
+			ReturnKind::Fallible,
+			"cv::decomposeEssentialMat",
+			"calib3d",
+			vec![
+				Field::new_desc(FieldDesc::new("E", TypeRefDesc::cv_input_array())),
+				Field::new_desc(FieldDesc::new("R1", TypeRefDesc::cv_output_array())),
+				Field::new_desc(FieldDesc::new("R2", TypeRefDesc::cv_output_array())),
+				Field::new_desc(FieldDesc::new("t", TypeRefDesc::cv_output_array())),
+			],
+			FuncCppBody::Auto,
+			FuncRustBody::Auto,
+			TypeRefDesc::void(),
+		));
+		let rust = func.gen_rust("4.9.0");
+		assert!(rust
+			.contains("pub fn decompose_essential_mat_tuple(e: &impl ToInputArray) -> Result<(core::Mat, core::Mat, core::Mat)> {"));
+		assert!(rust.contains("decompose_essential_mat(e, &mut r1, &mut r2, &mut t)?;"));
+		assert!(rust.contains("Ok((r1, r2, t))"));
+	}
+
+	#[test]
+	fn builder() {
+		fn with_default<'tu, 'ge>(mut desc: FieldDesc<'tu, 'ge>, default_value: &str) -> Field<'tu, 'ge> {
+			desc.default_value = Some(default_value.into());
+			Field::new_desc(desc)
+		}
+
+		let func = Func::new_desc(FuncDesc::new(
+			FuncKind::Function,
+			Constness::Mut,
+			ReturnKind::Fallible,
+			"cv::calcOpticalFlowPyrLK",
+			"video",
+			vec![
+				Field::new_desc(FieldDesc::new("prevImg", TypeRefDesc::cv_input_array())),
+				Field::new_desc(FieldDesc::new("nextImg", TypeRefDesc::cv_input_array())),
+				Field::new_desc(FieldDesc::new("prevPts", TypeRefDesc::cv_input_array())),
+				Field::new_desc(FieldDesc::new("nextPts", TypeRefDesc::cv_input_output_array())),
+				Field::new_desc(FieldDesc::new("status", TypeRefDesc::cv_output_array())),
+				Field::new_desc(FieldDesc::new("err", TypeRefDesc::cv_output_array())),
+				with_default(FieldDesc::new("winSize", TypeRefDesc::cv_size()), "Size(21,21)"),
+				with_default(FieldDesc::new("maxLevel", TypeRefDesc::int()), "3"),
+				with_default(
+					FieldDesc::new("criteria", TypeRefDesc::cv_term_criteria()),
+					"TermCriteria(TermCriteria::COUNT+TermCriteria::EPS,30,0.01)",
+				),
+				with_default(FieldDesc::new("flags", TypeRefDesc::int()), "0"),
+				with_default(FieldDesc::new("minEigThreshold", TypeRefDesc::double()), "1e-4"),
+			],
+			FuncCppBody::Auto,
+			FuncRustBody::Auto,
+			TypeRefDesc::void(),
+		));
+		let rust = func.gen_rust("4.9.0");
+		assert!(rust.contains("pub struct CalcOpticalFlowPyrLkBuilder {"));
+		assert!(rust.contains("win_size: core::Size,"));
+		assert!(rust.contains("win_size: core::Size::new(21, 21),"));
+		assert!(rust.contains("max_level: 3,"));
+		assert!(rust.contains(
+			"criteria: core::TermCriteria { typ: core::TermCriteria_COUNT + core::TermCriteria_EPS, max_count: 30, epsilon: 0.01 },"
+		));
+		assert!(rust.contains("flags: 0,"));
+		assert!(rust.contains("min_eig_threshold: 1e-4,"));
+		assert!(rust.contains("pub fn with_max_level(mut self, max_level: i32) -> Self {"));
+		assert!(rust.contains("pub fn with_criteria(mut self, criteria: core::TermCriteria) -> Self {"));
+		assert!(rust.contains(
+			"pub fn call(self, prev_img: &impl ToInputArray, next_img: &impl ToInputArray, prev_pts: &impl ToInputArray, next_pts: &mut impl ToInputOutputArray, status: &mut impl ToOutputArray, err: &mut impl ToOutputArray) -> Result<()> {"
+		));
+		assert!(rust.contains("calc_optical_flow_pyr_lk(prev_img, next_img, prev_pts, next_pts, status, err, self.win_size, self.max_level, self.criteria, self.flags, self.min_eig_threshold)"));
+	}
+
+	#[test]
+	fn null_receiver_check() {
+		assert_eq!(
+			"if self.as_raw_Feature2D().is_null() { return Err(crate::Error::new(crate::core::StsNullPtr, \"Null cv::Feature2D receiver\")); }",
+			rust_null_receiver_check("self.as_raw_Feature2D()", "cv::Feature2D")
+		);
+
+		// cv::Feature2D is not in settings::NULL_RECEIVER_CHECK
+		let func = Func::new_desc(FuncDesc::new(
+			FuncKind::InstanceMethod(ClassDesc::cv_feature2d()),
+			Constness::Const,
+			ReturnKind::Fallible,
+			"empty",
+			"features2d",
+			vec![],
+			FuncCppBody::Auto,
+			FuncRustBody::Auto,
+			TypeRefDesc::bool(),
+		));
+		let rust = func.gen_rust("4.9.0");
+		assert!(rust.contains("fn empty(&self) -> Result<bool> {"));
+		assert!(!rust.contains("is_null()"));
+
+		let func = Func::new_desc(FuncDesc::new(
+			FuncKind::InstanceMethod(ClassDesc::cv_algorithm()),
+			Constness::Const,
+			ReturnKind::Fallible,
+			"empty",
+			"core",
+			vec![],
+			FuncCppBody::Auto,
+			FuncRustBody::Auto,
+			TypeRefDesc::bool(),
+		));
+		let rust = func.gen_rust("4.9.0");
+		assert!(rust.contains(
+			"if self.as_raw_Algorithm().is_null() { return Err(crate::Error::new(crate::core::StsNullPtr, \"Null cv::Algorithm receiver\")); }"
+		));
+	}
+
+	#[test]
+	fn operator_trait() {
+		let const_ref = |cls| TypeRef::new_reference(TypeRef::new_class(cls).with_inherent_constness(Constness::Const));
+		let gmat = || Class::new_desc(ClassDesc::boxed("cv::GMat", "gapi"));
+		let func = Func::new_desc(FuncDesc::new(
+			FuncKind::FunctionOperator(OperatorKind::Add),
+			Constness::Mut,
+			ReturnKind::Fallible,
+			"cv::operator+",
+			"gapi",
+			vec![
+				Field::new_desc(FieldDesc::new("lhs", const_ref(gmat()))),
+				Field::new_desc(FieldDesc::new("rhs", const_ref(gmat()))),
+			],
+			FuncCppBody::Auto,
+			FuncRustBody::Auto,
+			TypeRef::new_class(gmat()),
+		));
+		let rust = func.gen_rust("4.9.0");
+		assert!(rust.contains("impl std::ops::Add<&crate::gapi::GMat> for &crate::gapi::GMat {"));
+		assert!(rust.contains("type Output = Result<crate::gapi::GMat>;"));
+		assert!(rust.contains("fn add(self, rhs: &crate::gapi::GMat) -> Self::Output {"));
+		assert!(rust.contains("add_gmat_gmat(self, rhs)"));
+
+		let mat_expr = || Class::new_desc(ClassDesc::boxed("cv::MatExpr", "core"));
+		let mat_func = |kind, name| {
+			Func::new_desc(FuncDesc::new(
+				FuncKind::FunctionOperator(kind),
+				Constness::Mut,
+				ReturnKind::Fallible,
+				name,
+				"core",
+				vec![
+					Field::new_desc(FieldDesc::new("a", const_ref(ClassDesc::cv_mat()))),
+					Field::new_desc(FieldDesc::new("b", const_ref(ClassDesc::cv_mat()))),
+				],
+				FuncCppBody::Auto,
+				FuncRustBody::Auto,
+				TypeRef::new_class(mat_expr()),
+			))
+		};
+
+		// Mat + Mat is implemented manually and cv::operator+(a, b) is not in settings::FUNC_OPERATOR_TRAIT
+		let rust = mat_func(OperatorKind::Add, "cv::operator+").gen_rust("4.9.0");
+		assert!(rust
+			.contains("pub fn add_mat_mat(a: &impl core::MatTraitConst, b: &impl core::MatTraitConst) -> Result<core::MatExpr> {"));
+		assert!(!rust.contains("impl std::ops::Add"));
+
+		let rust = mat_func(OperatorKind::And, "cv::operator&").gen_rust("4.9.0");
+		assert!(rust.contains("impl std::ops::BitAnd<&core::Mat> for &core::Mat {"));
+		assert!(rust.contains("type Output = Result<core::MatExpr>;"));
+		assert!(rust.contains("fn bitand(self, rhs: &core::Mat) -> Self::Output {"));
+		assert!(rust.contains("and_mat_mat(self, rhs)"));
+	}
+
+	#[test]
+	fn field_getter_by_value() {
+		let getter = |name: &str, type_ref: TypeRef<'static, 'static>, return_kind| {
+			Func::new_desc(FuncDesc::new(
+				FuncKind::FieldAccessor(
+					ClassDesc::cv_feature2d(),
+					Field::new_desc(FieldDesc::new(name, type_ref.clone())),
+				),
+				Constness::Const,
+				return_kind,
+				name,
+				"features2d",
+				vec![],
+				FuncCppBody::Auto,
+				FuncRustBody::Auto,
+				type_ref,
+			))
+		};
+		let rust = getter("count", TypeRefDesc::int(), ReturnKind::InfallibleNaked).gen_rust("4.9.0");
+		assert!(rust.contains("fn count(&self) -> i32 {"));
+		let rust = getter("anchor", TypeRefDesc::cv_point(), ReturnKind::InfallibleViaArg).gen_rust("4.9.0");
+		assert!(rust.contains("fn anchor(&self) -> core::Point {"));
+	}
+
+	#[test]
+	fn flags_arg() {
+		assert_eq!(
+			"flags: impl Into<crate::imgcodecs::ImreadModesFlags>",
+			rust_flags_arg_decl("flags", "crate::imgcodecs::ImreadModesFlags")
+		);
+		assert_eq!(
+			"let flags = crate::imgcodecs::ImreadModesFlags::bits(flags.into())",
+			rust_flags_arg_pre_call("flags", "crate::imgcodecs::ImreadModesFlags")
+		);
+
+		let imread = |doc_comment: &str| {
+			let mut desc = FuncDesc::new(
+				FuncKind::Function,
+				Constness::Mut,
+				ReturnKind::Fallible,
+				"cv::imread",
+				"imgcodecs",
+				vec![
+					Field::new_desc(FieldDesc::new("filename", TypeRefDesc::std_string())),
+					Field::new_desc(FieldDesc::new("flags", TypeRefDesc::int())),
+				],
+				FuncCppBody::Auto,
+				FuncRustBody::Auto,
+				TypeRef::new_class(ClassDesc::cv_mat()),
+			);
+			desc.doc_comment = doc_comment.into();
+			Func::new_desc(desc)
+		};
+		let rust =
+			imread("@brief Loads an image from a file.\n\n@param filename Name of file to be loaded.\n@param flags Flag that can take values of cv::ImreadModes").gen_rust("4.9.0");
+		assert!(
+			rust.contains(
+				"pub fn imread(filename: &str, flags: impl Into<crate::imgcodecs::ImreadModesFlags>) -> Result<core::Mat> {"
+			),
+			"{rust}"
+		);
+		assert!(
+			rust.contains("let flags = crate::imgcodecs::ImreadModesFlags::bits(flags.into());"),
+			"{rust}"
+		);
+
+		let rust = imread("@param filename Name of file to be loaded.\n@param flags Flags, see cv::IMREAD_COLOR").gen_rust("4.9.0");
+		assert!(rust.contains("flags: i32"), "{rust}");
+		assert!(!rust.contains("ImreadModesFlags"), "{rust}");
+	}
+
+	#[test]
+	fn flags_arg_doc() {
+		let doc = "@brief Reads\n@param buf Input array\n@param flags The same flags as in cv::imread, see\n  cv::ImreadModes.\n\n@param flagsEx other\n\\param [out] dst Output";
+		assert_eq!(
+			Some("The same flags as in cv::imread, see cv::ImreadModes."),
+			doc_comment_param(doc, "flags").as_deref()
+		);
+		assert_eq!(Some("other"), doc_comment_param(doc, "flagsEx").as_deref());
+		assert_eq!(Some("Output"), doc_comment_param(doc, "dst").as_deref());
+		assert_eq!(None, doc_comment_param(doc, "flag"));
+
+		assert!(mentions_name("see cv::ImreadModes.", "cv::ImreadModes"));
+		assert!(mentions_name("cv::ImreadModes", "cv::ImreadModes"));
+		assert!(!mentions_name("see cv::ImreadModesEx", "cv::ImreadModes"));
+		assert!(!mentions_name("see ocv::ImreadModes", "cv::ImreadModes"));
+	}
+
+	#[test]
+	fn out_return() {
+		let func = Func::new_desc(FuncDesc::new(
+			FuncKind::Function,
+			Constness::Mut,
+			ReturnKind::Fallible,
+			"cv::cvtColor",
+			"imgproc",
+			vec![
+				Field::new_desc(FieldDesc::new("src", TypeRefDesc::cv_input_array())),
+				Field::new_desc(FieldDesc::new("dst", TypeRefDesc::cv_output_array())),
+				Field::new_desc(FieldDesc::new("code", TypeRefDesc::int())),
+				Field::new_desc(FieldDesc::new("dstCn", TypeRefDesc::int())),
+			],
+			FuncCppBody::Auto,
+			FuncRustBody::Auto,
+			TypeRefDesc::void(),
+		));
+		let rust = func.gen_rust("4.9.0");
+		assert!(rust.contains("pub fn cvt_color_out(src: &impl ToInputArray, code: i32, dst_cn: i32) -> Result<core::Mat> {"));
+		assert!(rust.contains("let mut dst = core::Mat::default();"));
+		assert!(rust.contains("cvt_color(src, &mut dst, code, dst_cn)?;"));
+		assert!(rust.contains("Ok(dst)"));
+
+		let int_ref = || TypeRef::new_reference(TypeRefDesc::int());
+		let primitive_out = Field::new_desc(FieldDesc::new("count", int_ref()));
+		assert_eq!(Some("i32".to_string()), rust_out_return_output_type(&primitive_out));
+		let const_ref = Field::new_desc(FieldDesc::new(
+			"count",
+			TypeRef::new_reference(TypeRefDesc::int().with_inherent_constness(Constness::Const)),
+		));
+		assert_eq!(None, rust_out_return_output_type(&const_ref));
+		let by_value = Field::new_desc(FieldDesc::new("count", TypeRefDesc::int()));
+		assert_eq!(None, rust_out_return_output_type(&by_value));
+	}
+}
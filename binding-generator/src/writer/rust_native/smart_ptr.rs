@@ -8,7 +8,6 @@ use crate::field::{Field, FieldDesc};
 use crate::func::{FuncCppBody, FuncDesc, FuncKind, FuncRustBody, ReturnKind};
 use crate::smart_ptr::SmartPtrDesc;
 use crate::type_ref::{Constness, CppNameStyle, FishStyle, NameStyle, TypeRef, TypeRefKind};
-use crate::writer::rust_native::class::rust_generate_debug_fields;
 use crate::writer::rust_native::RustStringExt;
 use crate::{Class, CompiledInterpolation, Element, Func, IteratorExt, SmartPtr, StrExt};
 
@@ -118,11 +117,6 @@ impl RustNativeGeneratedElement for SmartPtr<'_, '_> {
 				),
 			]);
 			impls += &TRAIT_RAW_TPL.interpolate(&inter_vars);
-			let fields = cls.fields();
-			let mut field_const_methods = cls.field_methods(
-				fields.iter().filter(|f| f.exclude_kind().is_included()),
-				Some(Constness::Const),
-			);
 			for base in all_bases(&cls) {
 				let base_rust_local = base.rust_name(NameStyle::decl());
 				inter_vars.extend([
@@ -143,18 +137,10 @@ impl RustNativeGeneratedElement for SmartPtr<'_, '_> {
 				let extern_cast_to_base = method_cast_to_base(smartptr_class.clone(), base.type_ref(), &base_rust_local).identifier();
 				inter_vars.insert("extern_cast_to_base", extern_cast_to_base.into());
 				impls += &BASE_CAST_TPL.interpolate(&inter_vars);
-				let base_fields = base.fields();
-				let base_field_const_methods = base.field_methods(
-					base_fields.iter().filter(|f| f.exclude_kind().is_included()),
-					Some(Constness::Const),
-				);
-				field_const_methods.extend(base_field_const_methods);
 			}
-			let debug_fields = rust_generate_debug_fields(field_const_methods);
 			impls += &IMPL_DEBUG_TPL.interpolate(&HashMap::from([
 				("rust_full", rust_full.as_ref()),
 				("rust_localalias", rust_localalias.as_ref()),
-				("debug_fields", &debug_fields),
 			]));
 		};
 		if gen_ctor(&pointee_kind) {
@@ -332,3 +318,23 @@ fn method_get_inner_ptr<'tu, 'ge>(smartptr_class: Class<'tu, 'ge>, pointee_type:
 		return_type_ref,
 	))
 }
+
+#[cfg(test)]
+mod test {
+	use crate::type_ref::{TypeRefDesc, TypeRefKind};
+	use crate::writer::rust_native::RustNativeGeneratedElement;
+
+	use super::SmartPtrExt;
+
+	#[test]
+	fn alias() {
+		let type_ref = TypeRefDesc::ptr_of_cv_feature2d();
+		let TypeRefKind::SmartPtr(ptr) = type_ref.kind().into_owned() else {
+			panic!("Not a smart pointer: {type_ref:?}");
+		};
+		assert_eq!("PtrOfFeature2D", ptr.rust_localalias());
+		assert!(ptr
+			.gen_rust("4.9.0")
+			.contains("pub type PtrOfFeature2D = core::Ptr<crate::features2d::Feature2D>;"));
+	}
+}
@@ -1,7 +1,8 @@
 impl std::fmt::Debug for {{rust_local}} {
 	#[inline]
 	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-		f.debug_struct("{{rust_local}}"){{debug_fields}}
+		f.debug_struct("{{rust_local}}")
+			.field("ptr", &self.as_raw())
 			.finish()
 	}
 }
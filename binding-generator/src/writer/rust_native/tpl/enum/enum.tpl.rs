@@ -1,15 +1,27 @@
 {{doc_comment}}
 {{debug}}
-#[repr(C)]
+#[repr({{repr}})]
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+{{non_exhaustive}}
 pub enum {{rust_local}} {
 	{{enum_consts}}
 }
 
-impl TryFrom<i32> for {{rust_local}} {
+impl {{rust_local}} {
+	/// All variants of the enum in the declaration order, duplicate values are excluded
+	pub const ALL: &'static [Self] = &[{{all_variants}}];
+
+	/// Variant with the raw `value`, `None` if it doesn't match any of the variants
+	#[inline]
+	pub fn from_i32(value: i32) -> Option<Self> {
+		Self::try_from({{from_i32_value}}).ok()
+	}
+}
+
+impl TryFrom<{{int_type}}> for {{rust_local}} {
 	type Error = crate::Error;
 
-	fn try_from(value: i32) -> Result<Self, Self::Error> {
+	fn try_from(value: {{int_type}}) -> Result<Self, Self::Error> {
 		match value {
 			{{from_consts}}
 			_ => Err(crate::Error::new(crate::core::StsBadArg, format!("Value: {value} is not valid for enum: {{rust_full}}"))),
@@ -17,6 +29,7 @@ impl TryFrom<i32> for {{rust_local}} {
 	}
 }
 
-opencv_type_enum! { {{rust_full}} }
+opencv_type_enum! { {{rust_full}}, {{int_type}} }
+{{flags}}
 
 
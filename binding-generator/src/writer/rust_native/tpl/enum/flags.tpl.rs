@@ -0,0 +1,7 @@
+
+/// Combination of the [{{rust_local}}] values
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct {{rust_local}}Flags(pub {{int_type}});
+
+opencv_type_enum_flags! { {{rust_local}}Flags, {{rust_full}}, {{int_type}} }
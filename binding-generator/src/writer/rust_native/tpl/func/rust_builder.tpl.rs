@@ -0,0 +1,30 @@
+/// Builder for [{{refr}}] that allows setting its optional arguments one by one
+///
+/// [Default] sets all of the optional arguments to their C++ default values, use [{{struct_name}}::call] to run the function
+/// with the required arguments.
+#[derive(Copy, Clone, Debug)]
+{{attributes}}
+pub struct {{struct_name}} {
+	{{fields}}
+}
+
+{{attributes}}
+impl Default for {{struct_name}} {
+	#[inline]
+	fn default() -> Self {
+		Self {
+			{{defaults}}
+		}
+	}
+}
+
+{{attributes}}
+impl {{struct_name}} {
+	{{setters}}
+
+	/// Run [{{refr}}] with the required arguments and the optional arguments from this builder
+	#[inline]
+	pub fn call{{generic_decl}}(self, {{decl_args}}){{rv_rust_full}} {
+		{{name}}({{forward_args}})
+	}
+}
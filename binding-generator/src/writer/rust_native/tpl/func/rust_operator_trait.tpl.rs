@@ -0,0 +1,10 @@
+/// Operator version of [{{refr}}]
+{{attributes}}
+impl std::ops::{{trait_name}}<{{rhs_type}}> for {{lhs_type}} {
+	type Output = {{output}};
+
+	#[inline]
+	fn {{trait_method}}(self, rhs: {{rhs_type}}) -> Self::Output {
+		{{name}}(self, rhs)
+	}
+}
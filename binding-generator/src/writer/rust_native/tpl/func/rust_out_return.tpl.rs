@@ -0,0 +1,8 @@
+/// Alternative version of [{{refr}}] that returns its output argument instead of taking it by `&mut`
+#[inline]
+{{attributes}}
+{{visibility}}fn {{name}}_out{{generic_decl}}({{decl_args}}) -> Result<{{output_type}}> {
+	let mut {{output}} = {{output_type}}::default();
+	{{call_prefix}}{{name}}({{forward_args}})?;
+	Ok({{output}})
+}
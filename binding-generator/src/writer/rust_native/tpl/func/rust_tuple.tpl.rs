@@ -0,0 +1,8 @@
+/// Alternative version of [{{refr}}] that returns its output arguments as a tuple instead of taking them by `&mut`
+#[inline]
+{{attributes}}
+{{visibility}}fn {{name}}_tuple{{generic_decl}}({{decl_args}}) -> Result<{{rv_rust}}> {
+	{{pre_call_outputs}}
+	{{ret_receive}}{{call_prefix}}{{name}}({{forward_args}})?;
+	Ok({{ret}})
+}
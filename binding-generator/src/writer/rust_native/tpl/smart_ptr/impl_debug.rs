@@ -1,7 +1,8 @@
 impl std::fmt::Debug for {{rust_full}} {
 	#[inline]
 	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-		f.debug_struct("{{rust_localalias}}"){{debug_fields}}
+		f.debug_struct("{{rust_localalias}}")
+			.field("ptr", &self.as_raw())
 			.finish()
 	}
 }
@@ -10,9 +10,10 @@ use crate::type_ref::{
 };
 use crate::writer::rust_native::class::ClassExt;
 use crate::writer::rust_native::type_ref::render_lane::{
-	ByMoveRenderLane, CppPassByVoidPtrRenderLane, EnumRenderLane, FixedArrayRenderLane, FunctionRenderLane, InStringRenderLane,
-	IndirectRenderLane, Indirection, InputArrayRenderLane, InputOutputArrayRenderLane, OutStringRenderLane, OutputArrayRenderLane,
-	PrimitiveRenderLane, RenderLane, SimpleClassRenderLane, TraitClassRenderLane, VariableArrayRenderLane, VoidSliceRenderLane,
+	ByMoveRenderLane, ClosureRenderLane, CppPassByVoidPtrRenderLane, EnumRenderLane, FixedArrayRenderLane, FunctionRenderLane,
+	InStringRenderLane, IndirectRenderLane, Indirection, InputArrayRenderLane, InputOutputArrayRenderLane, OutStringRenderLane,
+	OutputArrayRenderLane, PrimitiveRenderLane, RenderLane, SimpleClassRenderLane, TraitClassRenderLane, VariableArrayRenderLane,
+	VoidSliceRenderLane,
 };
 use crate::StringExt;
 
@@ -99,6 +100,11 @@ impl TypeRefExt for TypeRef<'_, '_> {
 						kind => (Indirection::None, kind, Borrowed(self)),
 					};
 					match tref_kind.canonical().into_owned() {
+						TypeRefKind::Class(cls) if cls.is_std_function() => {
+							// unsupported signatures are excluded in Func::exclude_kind
+							let args = cls.std_function_closure_args().unwrap_or_default();
+							RenderLane::Closure(ClosureRenderLane::from_non_canonical_args(self.clone(), args))
+						}
 						TypeRefKind::Class(cls) => {
 							let cls_kind = cls.kind();
 							if cls_kind.is_simple() {
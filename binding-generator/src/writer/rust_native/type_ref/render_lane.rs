@@ -3,6 +3,7 @@ use std::fmt;
 
 pub use array::{FixedArrayRenderLane, VariableArrayRenderLane};
 pub use by_move::ByMoveRenderLane;
+pub use closure::ClosureRenderLane;
 pub use cpp_pass_by_void_ptr::CppPassByVoidPtrRenderLane;
 pub use enumeration::EnumRenderLane;
 pub use function::FunctionRenderLane;
@@ -19,6 +20,7 @@ use crate::writer::rust_native::type_ref::{Lifetime, TypeRefExt};
 
 mod array;
 mod by_move;
+mod closure;
 mod cpp_pass_by_void_ptr;
 mod enumeration;
 mod function;
@@ -62,6 +64,7 @@ pub enum RenderLane<'tu, 'ge> {
 	InString(InStringRenderLane<'tu, 'ge>),
 	OutString(OutStringRenderLane<'tu, 'ge>),
 	Function(FunctionRenderLane<'tu, 'ge>),
+	Closure(ClosureRenderLane<'tu, 'ge>),
 	Indirect(IndirectRenderLane<'tu, 'ge>),
 	CppPassByVoidPtr(CppPassByVoidPtrRenderLane<'tu, 'ge>),
 	VoidSlice(VoidSliceRenderLane<'tu, 'ge>),
@@ -84,6 +87,7 @@ impl<'tu, 'ge> RenderLane<'tu, 'ge> {
 			RenderLane::InString(rlane) => rlane,
 			RenderLane::OutString(rlane) => rlane,
 			RenderLane::Function(rlane) => rlane,
+			RenderLane::Closure(rlane) => rlane,
 			RenderLane::Indirect(rlane) => rlane,
 			RenderLane::CppPassByVoidPtr(rlane) => rlane,
 			RenderLane::VoidSlice(rlane) => rlane,
@@ -114,6 +118,7 @@ impl fmt::Debug for RenderLane<'_, '_> {
 			RenderLane::InString(_) => f.write_str("InString"),
 			RenderLane::OutString(_) => f.write_str("OutString"),
 			RenderLane::Function(_) => f.write_str("Function"),
+			RenderLane::Closure(_) => f.write_str("Closure"),
 			RenderLane::Indirect(_) => f.write_str("Indirect"),
 			RenderLane::CppPassByVoidPtr(_) => f.write_str("CppPassByVoidPtr"),
 			RenderLane::VoidSlice(_) => f.write_str("VoidSlice"),
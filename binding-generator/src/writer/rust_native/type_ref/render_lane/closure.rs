@@ -0,0 +1,156 @@
+use std::borrow::Cow;
+
+use crate::type_ref::{Constness, ExternDir, FishStyle, TypeRef};
+use crate::writer::rust_native::func::cpp_return_map;
+use crate::writer::rust_native::type_ref::{Lifetime, TypeRefExt};
+use crate::{CppNameStyle, IteratorExt};
+
+use super::{rust_arg_func_decl, rust_self_func_decl, RenderLaneTrait};
+
+/// `std::function` passed as a borrowed Rust closure, see `FUNC_CLOSURE_ARG`
+///
+/// On the extern level it's split into the trampoline function pointer and the `void*` userdata pointing to the closure, the
+/// C++ side wraps those into a lambda that copies the arguments to be received by Rust.
+pub struct ClosureRenderLane<'tu, 'ge> {
+	non_canonical: TypeRef<'tu, 'ge>,
+	args: Vec<TypeRef<'tu, 'ge>>,
+}
+
+impl<'tu, 'ge> ClosureRenderLane<'tu, 'ge> {
+	pub fn from_non_canonical_args(non_canonical: TypeRef<'tu, 'ge>, args: Vec<TypeRef<'tu, 'ge>>) -> Self {
+		Self { non_canonical, args }
+	}
+
+	fn rust_closure_type(&self) -> String {
+		format!(
+			"dyn Fn({args}) + Sync",
+			args = self
+				.args
+				.iter()
+				.map(|arg| arg.rust_return(FishStyle::No, Lifetime::Elided))
+				.join(", ")
+		)
+	}
+
+	fn rust_extern_trampoline_type(&self) -> String {
+		format!(
+			"unsafe extern \"C\" fn({args}*mut c_void)",
+			args = self
+				.args
+				.iter()
+				.map(|arg| format!("{}, ", arg.rust_extern(ExternDir::FromCpp)))
+				.join("")
+		)
+	}
+}
+
+impl RenderLaneTrait for ClosureRenderLane<'_, '_> {
+	fn rust_self_func_decl(&self, lifetime: Lifetime) -> Cow<'static, str> {
+		rust_self_func_decl(self.non_canonical.constness(), lifetime)
+	}
+
+	fn rust_arg_func_decl(&self, name: &str, lifetime: Lifetime) -> String {
+		rust_arg_func_decl(
+			name,
+			Constness::Const,
+			&format!("&{lifetime: <}({closure})", closure = self.rust_closure_type()),
+		)
+	}
+
+	fn rust_arg_pre_call(&self, name: &str, _is_function_infallible: bool) -> String {
+		let tramp_args = self
+			.args
+			.iter()
+			.enumerate()
+			.map(|(i, arg)| {
+				format!(
+					"arg_{i}: {extern_typ} => {typ}",
+					extern_typ = arg.rust_extern(ExternDir::FromCpp),
+					typ = arg.rust_return(FishStyle::No, Lifetime::Elided),
+				)
+			})
+			.join(", ");
+		format!("closure_arg!({name}_trampoline({tramp_args}) => {name}_userdata => {name})")
+	}
+
+	fn rust_arg_func_call(&self, name: &str) -> String {
+		format!("{name}_trampoline, {name}_userdata")
+	}
+
+	fn rust_extern_arg_func_decl(&self, name: &str) -> String {
+		format!(
+			"{tramp}, {name}_userdata: *mut c_void",
+			tramp = rust_arg_func_decl(name, Constness::Const, &self.rust_extern_trampoline_type()),
+		)
+	}
+
+	fn cpp_arg_func_decl(&self, name: &str) -> String {
+		let args = self.args.iter().map(|arg| format!("{}, ", arg.cpp_extern_return())).join("");
+		format!("void (*{name})({args}void*), void* {name}_userdata")
+	}
+
+	fn cpp_arg_func_call(&self, name: &str) -> String {
+		let (decl_args, call_args) = self
+			.args
+			.iter()
+			.enumerate()
+			.map(|(i, arg)| {
+				let arg_name = format!("arg_{i}");
+				let (call_arg, _) = cpp_return_map(arg, &arg_name, false);
+				(
+					format!("const {typ}& {arg_name}", typ = arg.cpp_name(CppNameStyle::Reference)),
+					format!("{call_arg}, "),
+				)
+			})
+			.unzip::<_, _, Vec<_>, Vec<_>>();
+		format!(
+			"[=]({decl_args}) {{ {name}({call_args}{name}_userdata); }}",
+			decl_args = decl_args.join(", "),
+			call_args = call_args.join(""),
+		)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use crate::class::ClassDesc;
+	use crate::type_ref::{Constness, TypeRef, TypeRefDesc};
+	use crate::writer::rust_native::type_ref::render_lane::RenderLaneTrait;
+	use crate::writer::rust_native::type_ref::Lifetime;
+	use crate::Class;
+
+	use super::ClosureRenderLane;
+
+	#[test]
+	fn closure_arg() {
+		let std_function = TypeRef::new_class(Class::new_desc(ClassDesc::system(
+			"std::function<void (const cv::Range &, int)>",
+			"core",
+		)));
+		let lane = ClosureRenderLane::from_non_canonical_args(
+			TypeRef::new_reference(std_function.with_inherent_constness(Constness::Const)),
+			vec![TypeRef::new_class(ClassDesc::cv_mat()), TypeRefDesc::int()],
+		);
+		assert_eq!(
+			"functor: &(dyn Fn(core::Mat, i32) + Sync)",
+			lane.rust_arg_func_decl("functor", Lifetime::Elided)
+		);
+		assert_eq!(
+			"closure_arg!(functor_trampoline(arg_0: *mut c_void => core::Mat, arg_1: i32 => i32) => functor_userdata => functor)",
+			lane.rust_arg_pre_call("functor", false)
+		);
+		assert_eq!("functor_trampoline, functor_userdata", lane.rust_arg_func_call("functor"));
+		assert_eq!(
+			"functor: unsafe extern \"C\" fn(*mut c_void, i32, *mut c_void), functor_userdata: *mut c_void",
+			lane.rust_extern_arg_func_decl("functor")
+		);
+		assert_eq!(
+			"void (*functor)(cv::Mat*, int, void*), void* functor_userdata",
+			lane.cpp_arg_func_decl("functor")
+		);
+		assert_eq!(
+			"[=](const cv::Mat& arg_0, const int& arg_1) { functor(new cv::Mat(arg_0), arg_1, functor_userdata); }",
+			lane.cpp_arg_func_call("functor")
+		);
+	}
+}
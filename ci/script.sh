@@ -65,7 +65,7 @@ export RUST_BACKTRACE=full
 
 cargo test -vv -p opencv-binding-generator
 
-FEATURES=rgb
+FEATURES=rgb,serde
 
 cargo test -vv --features "$FEATURES"
 cargo test --release -vv --features "$FEATURES"
@@ -41,9 +41,9 @@ pub(crate) mod mod_prelude {
 	pub use crate::mod_prelude_sys::*;
 	pub use crate::{
 		boxed_cast_base, boxed_cast_descendant, boxed_ref, extern_arg_send, extern_container_send, extern_receive, extern_send,
-		input_array_ref_forward, opencv_type_boxed, opencv_type_enum, opencv_type_simple, output_array_ref_forward, ptr_cast_base,
-		ptr_extern, ptr_extern_ctor, tuple_extern, vector_boxed_ref, vector_copy_non_bool, vector_extern, vector_non_copy_or_bool,
-		Result,
+		input_array_ref_forward, opencv_type_boxed, opencv_type_enum, opencv_type_enum_flags, opencv_type_simple,
+		output_array_ref_forward, ptr_cast_base, ptr_extern, ptr_extern_ctor, tuple_extern, vector_boxed_ref, vector_copy_non_bool,
+		vector_extern, vector_non_copy_or_bool, Result,
 	};
 }
 
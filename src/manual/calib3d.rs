@@ -0,0 +1,146 @@
+use crate::calib3d::{
+	fisheye_undistort_image, solve_pnp, StereoBM, StereoMatcher, StereoSGBM, StereoSGBM_MODE_SGBM, SOLVEPNP_ITERATIVE,
+};
+use crate::core::{Mat, Point2f, Point3d, Point3f, Ptr, Size, Vector};
+use crate::prelude::*;
+use crate::Result;
+
+/// Object pose relative to the camera as estimated by [solve_pnp_pose]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Pose {
+	/// Rotation vector in the axis-angle form, see [rodrigues](crate::calib3d::rodrigues) to convert it to a matrix
+	pub rvec: Point3d,
+	/// Translation vector
+	pub tvec: Point3d,
+	/// Whether the underlying solver reported success
+	pub success: bool,
+}
+
+/// Find the object pose from 3D-2D point correspondences
+///
+/// Wrapper around [solve_pnp] using the `SOLVEPNP_ITERATIVE` method without the extrinsic guess that returns the
+/// resulting rotation and translation vectors as a [Pose].
+pub fn solve_pnp_pose(
+	object_points: &Vector<Point3f>,
+	image_points: &Vector<Point2f>,
+	camera_matrix: &Mat,
+	dist_coeffs: &Mat,
+) -> Result<Pose> {
+	let mut rvec = Mat::default();
+	let mut tvec = Mat::default();
+	let success = solve_pnp(
+		object_points,
+		image_points,
+		camera_matrix,
+		dist_coeffs,
+		&mut rvec,
+		&mut tvec,
+		false,
+		SOLVEPNP_ITERATIVE,
+	)?;
+	Ok(Pose {
+		rvec: point3d_from_mat(&rvec)?,
+		tvec: point3d_from_mat(&tvec)?,
+		success,
+	})
+}
+
+#[inline]
+fn point3d_from_mat(m: &Mat) -> Result<Point3d> {
+	let v = m.data_typed::<f64>()?;
+	Ok(Point3d::new(v[0], v[1], v[2]))
+}
+
+/// Parameters of the semi-global block matching, see [StereoSGBM::create] for their meaning
+///
+/// The [Default] values are the same as the defaults of [StereoSGBM::create].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SgbmParams {
+	pub min_disparity: i32,
+	pub num_disparities: i32,
+	pub block_size: i32,
+	pub p1: i32,
+	pub p2: i32,
+	pub disp12_max_diff: i32,
+	pub pre_filter_cap: i32,
+	pub uniqueness_ratio: i32,
+	pub speckle_window_size: i32,
+	pub speckle_range: i32,
+	pub mode: i32,
+}
+
+impl Default for SgbmParams {
+	#[inline]
+	fn default() -> Self {
+		Self {
+			min_disparity: 0,
+			num_disparities: 16,
+			block_size: 3,
+			p1: 0,
+			p2: 0,
+			disp12_max_diff: 0,
+			pre_filter_cap: 0,
+			uniqueness_ratio: 0,
+			speckle_window_size: 0,
+			speckle_range: 0,
+			mode: StereoSGBM_MODE_SGBM,
+		}
+	}
+}
+
+/// Disparity map calculation for the rectified stereo pairs
+///
+/// Owns the underlying [StereoMatcher] so that it can be reused for the consecutive frames.
+#[derive(Debug)]
+pub struct StereoDisparity {
+	inner: Ptr<StereoMatcher>,
+}
+
+impl StereoDisparity {
+	/// Create a block matching ([StereoBM]) based calculator
+	pub fn bm(num_disparities: i32, block_size: i32) -> Result<Self> {
+		Ok(Self {
+			inner: StereoBM::create(num_disparities, block_size)?.into(),
+		})
+	}
+
+	/// Create a semi-global block matching ([StereoSGBM]) based calculator
+	pub fn sgbm(params: SgbmParams) -> Result<Self> {
+		let inner = StereoSGBM::create(
+			params.min_disparity,
+			params.num_disparities,
+			params.block_size,
+			params.p1,
+			params.p2,
+			params.disp12_max_diff,
+			params.pre_filter_cap,
+			params.uniqueness_ratio,
+			params.speckle_window_size,
+			params.speckle_range,
+			params.mode,
+		)?;
+		Ok(Self { inner: inner.into() })
+	}
+
+	/// Compute the disparity map for the 8-bit `left` and `right` images
+	///
+	/// Returns the `CV_16S` map of the same size as the input where each value is the disparity multiplied by 16.
+	pub fn compute(&mut self, left: &Mat, right: &Mat) -> Result<Mat> {
+		let mut disparity = Mat::default();
+		self.inner.compute(left, right, &mut disparity)?;
+		Ok(disparity)
+	}
+}
+
+impl Mat {
+	/// Remove the fisheye lens distortion from the image
+	///
+	/// Wrapper around [fisheye_undistort_image] that also uses the `camera_matrix` as the camera matrix of the undistorted
+	/// image of the same size. The `dist_coeffs` are the 4 distortion coefficients (k_1, k_2, k_3, k_4) of the fisheye
+	/// camera model.
+	pub fn fisheye_undistort(&self, camera_matrix: &Mat, dist_coeffs: &Mat) -> Result<Mat> {
+		let mut out = Mat::default();
+		fisheye_undistort_image(self, &mut out, camera_matrix, dist_coeffs, camera_matrix, Size::default())?;
+		Ok(out)
+	}
+}
@@ -7,7 +7,7 @@ use std::{fmt, ptr, slice};
 pub use mat_::*;
 
 use crate::boxed_ref::{BoxedRef, BoxedRefMut};
-use crate::core::{MatConstIterator, MatExpr, MatSize, Point, Rect, Scalar, Size, UMat};
+use crate::core::{MatConstIterator, MatExpr, MatSize, Point, Rect, Scalar, Size, UMat, CV_MAKETYPE};
 use crate::prelude::*;
 use crate::{core, input_output_array, input_output_array_vector, Error, Result};
 
@@ -27,7 +27,11 @@ unsafe fn convert_ptr_mut<'r, T>(r: *mut u8) -> &'r mut T {
 
 #[inline]
 fn match_format<T: DataType>(mat_type: i32) -> Result<()> {
-	let out_type = T::opencv_type();
+	match_type(mat_type, T::opencv_type())
+}
+
+#[inline]
+fn match_type(mat_type: i32, out_type: i32) -> Result<()> {
 	if mat_type == out_type {
 		Ok(())
 	} else {
@@ -270,6 +274,69 @@ impl Mat {
 			Err(Error::new(core::StsBadArg, "ROIs must not intersect"))
 		}
 	}
+
+	/// Forward discrete Fourier transform of this single-channel real `Mat`
+	///
+	/// Non-floating-point input is converted to `CV_32F` first. Returns the full-size 2-channel complex spectrum
+	/// (`DFT_COMPLEX_OUTPUT`), use [Mat::dft_inverse] to transform it back.
+	pub fn dft_forward(&self) -> Result<Mat> {
+		let mut out = Mat::default();
+		match self.depth() {
+			core::CV_32F | core::CV_64F => core::dft(self, &mut out, core::DFT_COMPLEX_OUTPUT, 0)?,
+			_ => {
+				let mut src = Mat::default();
+				self.convert_to(&mut src, core::CV_32F, 1., 0.)?;
+				core::dft(&src, &mut out, core::DFT_COMPLEX_OUTPUT, 0)?;
+			}
+		}
+		Ok(out)
+	}
+
+	/// Inverse discrete Fourier transform of this 2-channel complex spectrum produced by [Mat::dft_forward]
+	///
+	/// Returns the scaled (`DFT_SCALE`) single-channel real result (`DFT_REAL_OUTPUT`).
+	pub fn dft_inverse(&self) -> Result<Mat> {
+		let mut out = Mat::default();
+		core::dft(self, &mut out, core::DFT_INVERSE | core::DFT_SCALE | core::DFT_REAL_OUTPUT, 0)?;
+		Ok(out)
+	}
+
+	/// Magnitude of the Fourier spectrum of this single-channel real `Mat`
+	///
+	/// Returns the magnitude of [Mat::dft_forward] for every frequency, the spectrum is neither shifted nor log-scaled.
+	pub fn magnitude_spectrum(&self) -> Result<Mat> {
+		let spectrum = self.dft_forward()?;
+		let mut planes = core::Vector::<Mat>::new();
+		core::split(&spectrum, &mut planes)?;
+		let mut out = Mat::default();
+		core::magnitude(&planes.get(0)?, &planes.get(1)?, &mut out)?;
+		Ok(out)
+	}
+
+	/// Copy the channel `index` of this `Mat` into a new single-channel `Mat`
+	///
+	/// Returns an error if `index` is not in `0..channels()`.
+	pub fn extract_channel_owned(&self, index: i32) -> Result<Mat> {
+		let channels = self.channels();
+		if index < 0 || index >= channels {
+			return Err(Error::new(
+				core::StsOutOfRange,
+				format!("Channel index: {index} out of bounds 0..{channels}"),
+			));
+		}
+		let mut out = Mat::default();
+		core::extract_channel(self, &mut out, index)?;
+		Ok(out)
+	}
+
+	/// Mask of the elements lying within `[lower, upper]` in every channel
+	///
+	/// Returns the `CV_8UC1` `Mat` of the same size with 255 for the elements that are in range and 0 for the rest.
+	pub fn in_range(&self, lower: Scalar, upper: Scalar) -> Result<Mat> {
+		let mut out = Mat::default();
+		core::in_range(self, &lower, &upper, &mut out)?;
+		Ok(out)
+	}
 }
 
 pub struct MatIter<'m, T> {
@@ -543,6 +610,36 @@ pub trait MatTraitConstManual: MatTraitConst {
 		})
 	}
 
+	/// Convert `Mat` elements to the type `T` with optional scaling, values that don't fit `T` are saturated
+	///
+	/// The channel count of `T` must match the channel count of the `Mat`.
+	fn convert_clamped<T: DataType>(&self, alpha: f64, beta: f64) -> Result<Mat> {
+		let channels = self.channels();
+		let out_channels = T::opencv_channels();
+		if channels != out_channels {
+			return Err(Error::new(
+				core::StsUnmatchedFormats,
+				format!("Mat channel count is: {channels}, but requested type channel count is: {out_channels}"),
+			));
+		}
+		let mut out = Mat::default();
+		self.convert_to(&mut out, T::opencv_depth(), alpha, beta)?;
+		Ok(out)
+	}
+
+	/// Create a new `Mat` of the same size with elements of type `U` produced by applying `f` to every element of `self`
+	fn map<T: DataType, U: DataType, F: Fn(T) -> U>(&self, f: F) -> Result<Mat> {
+		match_format::<T>(self.typ()).and_then(|_| match_dims(self, 2))?;
+		let size = self.size()?;
+		let mut out = Mat::new_size_with_default(size, U::opencv_type(), Scalar::all(0.))?;
+		for row_n in 0..size.height {
+			let src = self.at_row::<T>(row_n)?;
+			let trg = out.at_row_mut::<U>(row_n)?;
+			trg.iter_mut().zip(src).for_each(|(trg, &src)| *trg = f(src));
+		}
+		Ok(out)
+	}
+
 	/// Returns an iterator over `Mat` elements and their positions
 	#[inline]
 	fn iter<T: DataType>(&self) -> Result<MatIter<T>>
@@ -650,6 +747,26 @@ pub trait MatTraitManual: MatTraitConstManual + MatTrait {
 		Ok(slice::from_raw_parts_mut(self.data_mut().cast::<T>(), total))
 	}
 
+	/// Call `f` for every pixel of a 2-dimensional `Mat` passing its position and mutable access to all of its `N` channels
+	///
+	/// The `Mat` type must have the depth of `T` and `N` times the channel count of `T`, e.g. `for_each_pixel_mut::<u8, 3, _>`
+	/// for `CV_8UC3`.
+	fn for_each_pixel_mut<T: DataType, const N: usize, F: FnMut(Point, &mut [T; N])>(&mut self, mut f: F) -> Result<()> {
+		let channels = T::opencv_channels() * i32::try_from(N)?;
+		match_type(self.typ(), CV_MAKETYPE(T::opencv_depth(), channels)).and_then(|_| match_dims(self, 2))?;
+		let size = self.size()?;
+		// safe because Mat::size() can't be negative
+		let width = size.width as usize;
+		for row_n in 0..size.height {
+			// safe because the type of the Mat has been checked and `[T; N]` has the layout of `N` consecutive `T`s
+			let row = unsafe { slice::from_raw_parts_mut(self.ptr_mut(row_n)?.cast::<[T; N]>(), width) };
+			for (col_n, pixel) in (0..).zip(row) {
+				f(Point::new(col_n, row_n), pixel);
+			}
+		}
+		Ok(())
+	}
+
 	/// Returns a mutable iterator over `Mat` elements and their positions
 	#[inline]
 	fn iter_mut<T: DataType>(&mut self) -> Result<MatIterMut<T>>
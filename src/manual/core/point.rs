@@ -1,13 +1,23 @@
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
-use num_traits::{NumCast, NumOps, ToPrimitive};
+use num_traits::{NumCast, NumOps, One, Signed, ToPrimitive, Zero};
 
-use crate::core::{Rect_, Size_, VecN};
+use crate::core::{Point2d, Point3_, RectScalar, Rect_, Size_, VecN};
 use crate::opencv_type_simple_generic;
 
+/// Coordinate differences `a - b` calculated in `f64`
+#[inline(always)]
+fn coord_delta<T: ToPrimitive>(a: Point_<T>, b: Point_<T>) -> (f64, f64) {
+	let to_f64 = |v: T| v.to_f64().unwrap_or(f64::NAN);
+	(to_f64(a.x) - to_f64(b.x), to_f64(a.y) - to_f64(b.y))
+}
+
 /// [docs.opencv.org](https://docs.opencv.org/master/db/d4e/classcv_1_1Point__.html)
 #[repr(C)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point_<T> {
 	pub x: T,
 	pub y: T,
@@ -19,6 +29,24 @@ impl<T> Point_<T> {
 		Self { x, y }
 	}
 
+	/// `Point` with both coordinates set to zero
+	#[inline]
+	pub fn zero() -> Self
+	where
+		T: Zero,
+	{
+		Self::new(T::zero(), T::zero())
+	}
+
+	/// Alias for [Point_::zero]
+	#[inline]
+	pub fn origin() -> Self
+	where
+		T: Zero,
+	{
+		Self::zero()
+	}
+
 	#[inline]
 	pub fn from_vec2(vec: VecN<T, 2>) -> Self {
 		let [x, y] = vec.0;
@@ -65,7 +93,7 @@ impl<T> Point_<T> {
 	#[inline]
 	pub fn inside(self, rect: Rect_<T>) -> bool
 	where
-		T: PartialOrd + Add<Output = T> + Copy,
+		T: RectScalar,
 	{
 		rect.contains(self)
 	}
@@ -80,6 +108,51 @@ impl<T> Point_<T> {
 		(self_x.powi(2) + self_y.powi(2)).sqrt()
 	}
 
+	/// Euclidean distance to `pt`
+	#[inline]
+	pub fn distance(self, pt: Point_<T>) -> f64
+	where
+		T: ToPrimitive,
+	{
+		self.distance_sq(pt).sqrt()
+	}
+
+	/// Squared Euclidean distance to `pt`, cheaper than [Point_::distance] when only comparing the distances
+	///
+	/// The coordinates are converted to `f64` before the subtraction, so it doesn't overflow for the integer types.
+	#[inline]
+	pub fn distance_sq(self, pt: Point_<T>) -> f64
+	where
+		T: ToPrimitive,
+	{
+		let (dx, dy) = coord_delta(self, pt);
+		dx * dx + dy * dy
+	}
+
+	/// Manhattan (taxicab) distance to `pt`, the sum of the absolute differences of the coordinates
+	#[inline]
+	pub fn manhattan_distance(self, pt: Point_<T>) -> f64
+	where
+		T: ToPrimitive,
+	{
+		let (dx, dy) = coord_delta(self, pt);
+		dx.abs() + dy.abs()
+	}
+
+	/// Linear interpolation between this point (`t` = 0) and `other` (`t` = 1)
+	///
+	/// The result is always calculated in `f64`, `t` outside of `[0, 1]` extrapolates along the same line.
+	#[inline]
+	pub fn lerp(self, other: Point_<T>, t: f64) -> Point2d
+	where
+		T: ToPrimitive,
+	{
+		let to_f64 = |v: T| v.to_f64().unwrap_or(f64::NAN);
+		let (from_x, from_y) = (to_f64(self.x), to_f64(self.y));
+		let (to_x, to_y) = (to_f64(other.x), to_f64(other.y));
+		Point2d::new(from_x + (to_x - from_x) * t, from_y + (to_y - from_y) * t)
+	}
+
 	/// Cast `Point` to the other coord type
 	#[inline]
 	pub fn to<D: NumCast>(self) -> Option<Point_<D>>
@@ -93,6 +166,117 @@ impl<T> Point_<T> {
 	pub fn to_vec2(self) -> VecN<T, 2> {
 		VecN::<_, 2>::from_array([self.x, self.y])
 	}
+
+	/// Wrap the coordinates into `[0, bounds.width)` and `[0, bounds.height)` as in the toroidal space
+	///
+	/// Negative coordinates wrap around to the positive side. Panics for integer types if `bounds` has zero width or
+	/// height.
+	#[inline]
+	pub fn wrap(self, bounds: Size_<T>) -> Self
+	where
+		T: NumOps + Copy,
+	{
+		Self::new(
+			(self.x % bounds.width + bounds.width) % bounds.width,
+			(self.y % bounds.height + bounds.height) % bounds.height,
+		)
+	}
+
+	/// Absolute value of each of the coordinates
+	#[inline]
+	pub fn abs(self) -> Self
+	where
+		T: Signed,
+	{
+		Self::new(self.x.abs(), self.y.abs())
+	}
+
+	/// Reflect the point through the origin, same as the rotation by 180 degrees
+	#[inline]
+	pub fn negate(self) -> Self
+	where
+		T: Neg<Output = T>,
+	{
+		Self::new(-self.x, -self.y)
+	}
+
+	/// Rotate the point by 90 degrees counterclockwise (in the coordinate system with `y` pointing up), i.e. `(-y, x)`
+	#[inline]
+	pub fn perpendicular(self) -> Self
+	where
+		T: Neg<Output = T>,
+	{
+		Self::new(-self.y, self.x)
+	}
+
+	/// Convert `Point` to the homogeneous coordinates by appending 1 as `z`
+	#[inline]
+	pub fn to_homogeneous(self) -> Point3_<T>
+	where
+		T: One,
+	{
+		Point3_::new(self.x, self.y, T::one())
+	}
+
+	/// Format `Point` as `(x, y)` with each coordinate having exactly `digits` decimal places
+	///
+	/// The precision is ignored for the integer coordinate types.
+	#[inline]
+	pub fn format_precision(&self, digits: usize) -> String
+	where
+		T: fmt::Display,
+	{
+		format!("({:.*}, {:.*})", digits, self.x, digits, self.y)
+	}
+}
+
+impl Point_<i32> {
+	/// Same as [Point_::dot], but calculated in `i64`
+	///
+	/// It doesn't overflow for any input except for the single case when all 4 coordinates are `i32::MIN`.
+	#[inline]
+	pub fn dot_i64(&self, pt: Point_<i32>) -> i64 {
+		self.x as i64 * pt.x as i64 + self.y as i64 * pt.y as i64
+	}
+}
+
+impl Point_<f64> {
+	/// Round each coordinate to the nearest integer, the halves are rounded away from zero
+	///
+	/// The coordinates outside of the `i32` range saturate and NaN becomes 0, same for [Point_::floor] and [Point_::ceil].
+	#[inline]
+	pub fn round(&self) -> Point_<i32> {
+		Point_::new(self.x.round() as i32, self.y.round() as i32)
+	}
+
+	/// Round each coordinate down to the integer
+	#[inline]
+	pub fn floor(&self) -> Point_<i32> {
+		Point_::new(self.x.floor() as i32, self.y.floor() as i32)
+	}
+
+	/// Round each coordinate up to the integer
+	#[inline]
+	pub fn ceil(&self) -> Point_<i32> {
+		Point_::new(self.x.ceil() as i32, self.y.ceil() as i32)
+	}
+
+	/// Rotate the point about `center` by `angle_rad` radians
+	///
+	/// Positive angle rotates counterclockwise in the coordinate system with `y` pointing up, which is clockwise on the
+	/// image where `y` points down, see also [Point_::perpendicular].
+	#[inline]
+	pub fn rotate(&self, center: Point_<f64>, angle_rad: f64) -> Point_<f64> {
+		let (sin, cos) = angle_rad.sin_cos();
+		let (dx, dy) = (self.x - center.x, self.y - center.y);
+		Point_::new(center.x + dx * cos - dy * sin, center.y + dx * sin + dy * cos)
+	}
+
+	/// Same as [Point_::rotate], but the angle is in degrees
+	#[inline]
+	pub fn rotate_deg(&self, center: Point_<f64>, angle_deg: f64) -> Point_<f64> {
+		self.rotate(center, angle_deg.to_radians())
+	}
 }
 
 impl<T> From<(T, T)> for Point_<T> {
@@ -102,6 +286,27 @@ impl<T> From<(T, T)> for Point_<T> {
 	}
 }
 
+impl<T> From<[T; 2]> for Point_<T> {
+	#[inline]
+	fn from([x, y]: [T; 2]) -> Self {
+		Self::new(x, y)
+	}
+}
+
+impl<T> From<Point_<T>> for (T, T) {
+	#[inline]
+	fn from(s: Point_<T>) -> Self {
+		(s.x, s.y)
+	}
+}
+
+impl<T> From<Point_<T>> for [T; 2] {
+	#[inline]
+	fn from(s: Point_<T>) -> Self {
+		[s.x, s.y]
+	}
+}
+
 impl<T> From<VecN<T, 2>> for Point_<T> {
 	#[inline]
 	fn from(s: VecN<T, 2>) -> Self {
@@ -171,6 +376,15 @@ impl<T: AddAssign> AddAssign for Point_<T> {
 	}
 }
 
+impl<T: Zero> Sum for Point_<T>
+where
+	Self: Add<Output = Self>,
+{
+	fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+		iter.fold(Self::zero(), Add::add)
+	}
+}
+
 impl<T: SubAssign> SubAssign for Point_<T> {
 	fn sub_assign(&mut self, rhs: Self) {
 		self.x -= rhs.x;
@@ -192,4 +406,21 @@ impl<T: DivAssign + Copy> DivAssign<T> for Point_<T> {
 	}
 }
 
+/// Same format as `operator<<` of OpenCV: `[x, y]`
+impl<T: fmt::Display> fmt::Display for Point_<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "[{}, {}]", self.x, self.y)
+	}
+}
+
+/// Average position of the `points` calculated in `f64`, `None` if `points` is empty
+#[inline]
+pub fn centroid<T: ToPrimitive + Copy>(points: &[Point_<T>]) -> Option<Point2d> {
+	if points.is_empty() {
+		return None;
+	}
+	let sum = points.iter().map(|pt| pt.to::<f64>()).sum::<Option<Point2d>>()?;
+	Some(sum / points.len() as f64)
+}
+
 opencv_type_simple_generic! { Point_<Copy> }
@@ -92,6 +92,21 @@ impl<T> Point3_<T> {
 	pub fn to_vec3(self) -> VecN<T, 3> {
 		VecN::<_, 3>::from_array([self.x, self.y, self.z])
 	}
+
+	/// Convert `Point3` from the homogeneous coordinates by dividing `x` and `y` by `z`
+	///
+	/// Returns `None` if `z` is zero, i.e. the point is at infinity.
+	#[inline]
+	pub fn from_homogeneous(self) -> Option<Point_<T>>
+	where
+		T: NumOps + Zero + PartialEq + Copy,
+	{
+		if self.z == T::zero() {
+			None
+		} else {
+			Some(Point_::new(self.x / self.z, self.y / self.z))
+		}
+	}
 }
 
 impl<T> From<(T, T, T)> for Point3_<T> {
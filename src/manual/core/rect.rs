@@ -1,31 +1,81 @@
-use std::ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, Mul, Sub, SubAssign};
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, Mul, MulAssign, Sub, SubAssign};
 
-use num_traits::{NumCast, NumOps, ToPrimitive, Zero};
+use num_traits::{CheckedMul, NumCast, NumOps, One, Signed, Zero};
 
-use crate::core::{Point_, Size_};
-use crate::opencv_type_simple_generic;
+use crate::core::{GeometryExtent, Point_, RotatedRect, Size_};
+use crate::{opencv_type_simple_generic, Result};
 
+/// `true` if `v` is not comparable to itself, i.e. NaN
+#[inline(always)]
+fn is_nan<T: PartialOrd>(v: &T) -> bool {
+	v.partial_cmp(v).is_none()
+}
+
+/// Smaller of the 2 values, NaN if either of them is NaN
 #[inline(always)]
 fn partial_min<T: PartialOrd>(a: T, b: T) -> T {
-	if a <= b {
+	if a <= b || is_nan(&a) {
 		a
 	} else {
 		b
 	}
 }
 
+/// Larger of the 2 values, NaN if either of them is NaN
 #[inline(always)]
 fn partial_max<T: PartialOrd>(a: T, b: T) -> T {
-	if b >= a {
+	if b >= a || is_nan(&b) {
 		b
 	} else {
 		a
 	}
 }
 
+mod private {
+	use num_traits::NumCast;
+
+	pub trait Sealed: Sized {
+		/// Cast from `f64` rounding to the nearest value for the integer types
+		fn from_f64_rounded(v: f64) -> Option<Self>;
+	}
+
+	impl Sealed for i32 {
+		#[inline]
+		fn from_f64_rounded(v: f64) -> Option<Self> {
+			<Self as NumCast>::from(v.round())
+		}
+	}
+
+	impl Sealed for f32 {
+		#[inline]
+		fn from_f64_rounded(v: f64) -> Option<Self> {
+			<Self as NumCast>::from(v)
+		}
+	}
+
+	impl Sealed for f64 {
+		#[inline]
+		fn from_f64_rounded(v: f64) -> Option<Self> {
+			Some(v)
+		}
+	}
+}
+
+/// Coordinate and size type of [Rect_], implemented for `i32`, `f32` and `f64`
+///
+/// Bundles the arithmetic, ordering and casting bounds required by the [Rect_] methods. This trait is sealed.
+pub trait RectScalar: private::Sealed + NumOps + NumCast + Zero + PartialOrd + Default + Copy {}
+
+impl RectScalar for i32 {}
+impl RectScalar for f32 {}
+impl RectScalar for f64 {}
+
 /// [docs.opencv.org](https://docs.opencv.org/master/d2/d44/classcv_1_1Rect__.html)
 #[repr(C)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rect_<T> {
 	pub x: T,
 	pub y: T,
@@ -44,16 +94,84 @@ impl<T> Rect_<T> {
 		Self::new(pt.x, pt.y, sz.width, sz.height)
 	}
 
+	/// Shorter alias for [Rect_::from_point_size]
+	#[inline]
+	pub fn at(pt: Point_<T>, sz: Size_<T>) -> Self {
+		Self::from_point_size(pt, sz)
+	}
+
+	/// Create a rectangle of size `sz` centered at `center`
+	///
+	/// The top-left corner is `center - sz / 2`. For the integer types the halves of the width and height are truncated,
+	/// which makes it the exact inverse of [Rect_::center].
+	#[inline]
+	pub fn from_center_size(center: Point_<T>, sz: Size_<T>) -> Self
+	where
+		T: RectScalar + One,
+	{
+		let two = T::one() + T::one();
+		Self::new(center.x - sz.width / two, center.y - sz.height / two, sz.width, sz.height)
+	}
+
+	/// Smallest rectangle that has `pt1` and `pt2` as its opposite corners
+	///
+	/// NaN in any of the coordinates propagates to the corresponding position and size components of the result, use
+	/// [Rect_::try_from_points] to detect that.
 	#[inline]
 	pub fn from_points(pt1: Point_<T>, pt2: Point_<T>) -> Self
 	where
-		T: PartialOrd + Sub<Output = T> + Copy,
+		T: RectScalar,
 	{
 		let x = partial_min(pt1.x, pt2.x);
 		let y = partial_min(pt1.y, pt2.y);
 		Self::new(x, y, partial_max(pt1.x, pt2.x) - x, partial_max(pt1.y, pt2.y) - y)
 	}
 
+	/// Same as [Rect_::from_points], but returns `None` if any of the coordinates is NaN
+	#[inline]
+	pub fn try_from_points(pt1: Point_<T>, pt2: Point_<T>) -> Option<Self>
+	where
+		T: RectScalar,
+	{
+		if [pt1.x, pt1.y, pt2.x, pt2.y].iter().any(is_nan) {
+			None
+		} else {
+			Some(Self::from_points(pt1, pt2))
+		}
+	}
+
+	/// Rectangle with `tl` as its top-left corner and `br` as its bottom-right one
+	///
+	/// Unlike [Rect_::from_points] the corners are not reordered, the size is just `br - tl`. In debug builds it panics if
+	/// `br` lies to the left of or above `tl`.
+	#[inline]
+	pub fn from_corners(tl: Point_<T>, br: Point_<T>) -> Self
+	where
+		T: RectScalar,
+	{
+		debug_assert!(br.x >= tl.x && br.y >= tl.y, "br must not lie to the left of or above tl");
+		Self::new(tl.x, tl.y, br.x - tl.x, br.y - tl.y)
+	}
+
+	/// Tightest rectangle enclosing all of the `points`, `None` if there are no points
+	///
+	/// Like with [Rect_::from_points] the extreme points lie on the edges of the result, so for a single point the result
+	/// is an empty rectangle at that point.
+	pub fn bounding(points: impl IntoIterator<Item = Point_<T>>) -> Option<Self>
+	where
+		T: RectScalar,
+	{
+		let mut points = points.into_iter();
+		let first = points.next()?;
+		let (tl, br) = points.fold((first, first), |(tl, br), pt| {
+			(
+				Point_::new(partial_min(tl.x, pt.x), partial_min(tl.y, pt.y)),
+				Point_::new(partial_max(br.x, pt.x), partial_max(br.y, pt.y)),
+			)
+		});
+		Some(Self::from_points(tl, br))
+	}
+
 	#[inline]
 	pub const fn tl(&self) -> Point_<T>
 	where
@@ -65,11 +183,24 @@ impl<T> Rect_<T> {
 	#[inline]
 	pub fn br(&self) -> Point_<T>
 	where
-		T: Add<Output = T> + Copy,
+		T: RectScalar,
 	{
 		Point_::new(self.x + self.width, self.y + self.height)
 	}
 
+	/// Geometric center of the rectangle
+	///
+	/// Calculated as `tl + size / 2`. For the integer types the halves of the width and height are truncated, so e.g. the
+	/// center of `Rect::new(0, 0, 5, 3)` is `Point::new(2, 1)`.
+	#[inline]
+	pub fn center(&self) -> Point_<T>
+	where
+		T: RectScalar + One,
+	{
+		let two = T::one() + T::one();
+		Point_::new(self.x + self.width / two, self.y + self.height / two)
+	}
+
 	#[inline]
 	pub const fn size(&self) -> Size_<T>
 	where
@@ -78,18 +209,60 @@ impl<T> Rect_<T> {
 		Size_::new(self.width, self.height)
 	}
 
+	/// Product of the width and height
+	///
+	/// For the integer types the multiplication can overflow for large rectangles: it panics in debug builds and wraps in
+	/// release ones. Use [Rect_::checked_area] or [Rect_::area_i64] when this is a concern.
 	#[inline]
 	pub fn area(&self) -> T
 	where
-		T: Mul<Output = T> + Copy,
+		T: RectScalar,
 	{
 		self.width * self.height
 	}
 
+	/// Product of the width and height, `None` if the multiplication overflows
+	#[inline]
+	pub fn checked_area(&self) -> Option<T>
+	where
+		T: CheckedMul,
+	{
+		self.width.checked_mul(&self.height)
+	}
+
+	/// Area of the intersection `self & other` calculated without constructing the intersection rectangle, 0 if the
+	/// rectangles are disjoint
+	///
+	/// The same overflow considerations as for [Rect_::area] apply, use [Rect_::intersection_area_i64] for the widened
+	/// variant.
+	#[inline]
+	pub fn intersection_area(&self, other: &Rect_<T>) -> T
+	where
+		T: RectScalar,
+	{
+		let (width, height) = self.intersection_extent(other);
+		width * height
+	}
+
+	/// Width and height of the intersection `self & other`, both are 0 if the rectangles are disjoint
+	#[inline]
+	fn intersection_extent(&self, other: &Rect_<T>) -> (T, T)
+	where
+		T: RectScalar,
+	{
+		let width = partial_min(self.x + self.width, other.x + other.width) - partial_max(self.x, other.x);
+		let height = partial_min(self.y + self.height, other.y + other.height) - partial_max(self.y, other.y);
+		if width > T::zero() && height > T::zero() {
+			(width, height)
+		} else {
+			(T::zero(), T::zero())
+		}
+	}
+
 	#[inline]
 	pub fn empty(&self) -> bool
 	where
-		T: PartialOrd + Zero,
+		T: RectScalar,
 	{
 		self.width <= T::zero() || self.height <= T::zero()
 	}
@@ -97,16 +270,193 @@ impl<T> Rect_<T> {
 	#[inline]
 	pub fn contains(&self, pt: Point_<T>) -> bool
 	where
-		T: PartialOrd + Add<Output = T> + Copy,
+		T: RectScalar,
 	{
 		self.x <= pt.x && pt.x < self.x + self.width && self.y <= pt.y && pt.y < self.y + self.height
 	}
 
+	/// Checks whether `other` lies fully inside `self`
+	///
+	/// An empty `other` is always considered to be contained.
+	#[inline]
+	pub fn contains_rect(&self, other: &Rect_<T>) -> bool
+	where
+		T: RectScalar,
+	{
+		other.empty()
+			|| self.x <= other.x
+				&& self.y <= other.y
+				&& other.x + other.width <= self.x + self.width
+				&& other.y + other.height <= self.y + self.height
+	}
+
+	/// Clip the rectangle to the `bounds`, same as the intersection `self & bounds`
+	///
+	/// The result is an empty (all zero) `Rect` if `self` lies fully outside of `bounds`.
+	#[inline]
+	pub fn clip(&self, bounds: &Rect_<T>) -> Rect_<T>
+	where
+		T: RectScalar,
+	{
+		*self & *bounds
+	}
+
+	/// Clip the rectangle to the image of size `sz`, i.e. to `0..sz.width` horizontally and `0..sz.height` vertically
+	///
+	/// The result can be used as a ROI of the `Mat` of that size.
+	#[inline]
+	pub fn clip_to_size(&self, sz: Size_<T>) -> Rect_<T>
+	where
+		T: RectScalar,
+	{
+		self.clip(&sz.at_origin())
+	}
+
+	/// Intersection over union (Jaccard index) of the two rectangles
+	///
+	/// Returns the value in `[0, 1]`: 0 for the disjoint rectangles or when either of them is empty and 1 for the identical
+	/// ones. The areas are calculated in `f64`.
+	pub fn iou(&self, other: &Rect_<T>) -> f64
+	where
+		T: RectScalar,
+	{
+		if self.empty() || other.empty() {
+			return 0.;
+		}
+		// multiply in f64, area() can overflow T for the large rectangles
+		let area = |r: &Rect_<T>| Some(r.width.to_f64()? * r.height.to_f64()?);
+		match (area(self), area(other), area(&(*self & *other))) {
+			(Some(self_area), Some(other_area), Some(intersection)) => {
+				let union = self_area + other_area - intersection;
+				if union > 0. {
+					intersection / union
+				} else {
+					0.
+				}
+			}
+			_ => 0.,
+		}
+	}
+
+	/// Rectangle covering the same region but with non-negative width and height
+	///
+	/// A negative width (height) moves the origin left (up) by that amount, so e.g. `Rect::new(10, 10, -4, 5)` becomes
+	/// `Rect::new(6, 10, 4, 5)`.
+	#[inline]
+	pub fn normalize(&self) -> Rect_<T>
+	where
+		T: RectScalar,
+	{
+		let (x, width) = if self.width < T::zero() {
+			(self.x + self.width, T::zero() - self.width)
+		} else {
+			(self.x, self.width)
+		};
+		let (y, height) = if self.height < T::zero() {
+			(self.y + self.height, T::zero() - self.height)
+		} else {
+			(self.y, self.height)
+		};
+		Rect_::new(x, y, width, height)
+	}
+
+	/// Rectangle with the absolute values of width and height and the same origin
+	///
+	/// Unlike [Rect_::normalize] this doesn't preserve the covered region for the negative dimensions.
+	#[inline]
+	pub fn abs_dimensions(&self) -> Rect_<T>
+	where
+		T: RectScalar + Signed,
+	{
+		Rect_::new(self.x, self.y, self.width.abs(), self.height.abs())
+	}
+
+	/// Grow the rectangle by `dx` on the left and right sides and by `dy` on the top and bottom sides
+	///
+	/// Negative margins shrink the rectangle, shrinking past zero produces a negative width or height which is reported
+	/// by [Rect_::empty].
+	#[inline]
+	pub fn inflate(&self, dx: T, dy: T) -> Rect_<T>
+	where
+		T: RectScalar,
+	{
+		Rect_::new(self.x - dx, self.y - dy, self.width + dx + dx, self.height + dy + dy)
+	}
+
+	/// Shrink the rectangle on each side by `pct` percent of its width (left and right) and height (top and bottom)
+	///
+	/// The insets are calculated in `f64` and rounded to the nearest value for the integer types. Negative `pct` grows the
+	/// rectangle instead.
+	pub fn inset_percent(&self, pct: f64) -> Rect_<T>
+	where
+		T: RectScalar,
+	{
+		let inset = |dim: T| {
+			dim.to_f64()
+				.and_then(|dim| T::from_f64_rounded(dim * pct / 100.))
+				.unwrap_or_else(T::zero)
+		};
+		let dx = inset(self.width);
+		let dy = inset(self.height);
+		Rect_::new(self.x + dx, self.y + dy, self.width - dx - dx, self.height - dy - dy)
+	}
+
+	/// Linear interpolation between this rectangle (`t` = 0) and `other` (`t` = 1)
+	///
+	/// All 4 components are interpolated in `f64` and rounded to the nearest value for the integer types. `t` outside of
+	/// `[0, 1]` extrapolates.
+	pub fn lerp(&self, other: &Rect_<T>, t: f64) -> Rect_<T>
+	where
+		T: RectScalar,
+	{
+		let lerp = |from: T, to: T| {
+			from
+				.to_f64()
+				.zip(to.to_f64())
+				.and_then(|(from, to)| T::from_f64_rounded(from + (to - from) * t))
+				.unwrap_or(from)
+		};
+		Rect_::new(
+			lerp(self.x, other.x),
+			lerp(self.y, other.y),
+			lerp(self.width, other.width),
+			lerp(self.height, other.height),
+		)
+	}
+
+	/// Apply `f` to each of the 4 components of the rectangle
+	#[inline]
+	pub fn map<F: Fn(T) -> T>(&self, f: F) -> Rect_<T>
+	where
+		T: Copy,
+	{
+		Rect_::new(f(self.x), f(self.y), f(self.width), f(self.height))
+	}
+
+	/// Scale the position and size of the rectangle by `fx` horizontally and by `fy` vertically
+	///
+	/// The calculation is done in `f64` and the result is not rounded, use [Rect_::to] to cast it back to `i32` with
+	/// truncation or [Rect_::align_to_pixels] to round it. For the uniform scaling by the factor of the same type use
+	/// `rect * factor`.
+	#[inline]
+	pub fn scale_f64(&self, fx: f64, fy: f64) -> Rect_<f64>
+	where
+		T: RectScalar,
+	{
+		let to_f64 = |v: T| v.to_f64().unwrap_or(0.);
+		Rect_::new(
+			to_f64(self.x) * fx,
+			to_f64(self.y) * fy,
+			to_f64(self.width) * fx,
+			to_f64(self.height) * fy,
+		)
+	}
+
 	/// Cast `Rect` to the other coord and size type
 	#[inline]
 	pub fn to<D: NumCast>(&self) -> Option<Rect_<D>>
 	where
-		T: ToPrimitive + Copy,
+		T: RectScalar,
 	{
 		Some(Rect_ {
 			x: D::from(self.x)?,
@@ -117,6 +467,63 @@ impl<T> Rect_<T> {
 	}
 }
 
+/// Policy for aligning a floating point `Rect` to the integer pixel boundaries, see [Rect_::align_to_pixels]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AlignMode {
+	/// Largest pixel-aligned rectangle fully contained in the source rectangle, edges are rounded inwards
+	Inner,
+	/// Smallest pixel-aligned rectangle fully containing the source rectangle, edges are rounded outwards
+	Outer,
+	/// Every edge is rounded to the nearest pixel boundary
+	Round,
+}
+
+impl Rect_<f64> {
+	/// Align the edges of the rectangle to the integer pixel boundaries according to `mode`
+	///
+	/// The resulting width and height are never negative, e.g. [AlignMode::Inner] for a rectangle containing no whole pixel
+	/// produces an empty `Rect`.
+	pub fn align_to_pixels(&self, mode: AlignMode) -> Rect_<i32> {
+		let (left, top, right, bottom) = (self.x, self.y, self.x + self.width, self.y + self.height);
+		let (left, top, right, bottom) = match mode {
+			AlignMode::Inner => (left.ceil(), top.ceil(), right.floor(), bottom.floor()),
+			AlignMode::Outer => (left.floor(), top.floor(), right.ceil(), bottom.ceil()),
+			AlignMode::Round => (left.round(), top.round(), right.round(), bottom.round()),
+		};
+		Rect_::new(
+			left as i32,
+			top as i32,
+			(right - left).max(0.) as i32,
+			(bottom - top).max(0.) as i32,
+		)
+	}
+}
+
+impl Rect_<i32> {
+	/// Product of the width and height widened to `i64`, it never overflows
+	#[inline]
+	pub fn area_i64(&self) -> i64 {
+		self.width as i64 * self.height as i64
+	}
+
+	/// Same as [Rect_::intersection_area], but the area is widened to `i64` so it never overflows
+	#[inline]
+	pub fn intersection_area_i64(&self, other: &Rect_<i32>) -> i64 {
+		let (width, height) = self.intersection_extent(other);
+		width as i64 * height as i64
+	}
+}
+
+impl Rect_<f32> {
+	/// `RotatedRect` with the same center and size as this rectangle and the angle of 0
+	///
+	/// Use [RotatedRect::bounding_rect2f] for the reverse conversion.
+	#[inline]
+	pub fn to_rotated_rect(&self) -> Result<RotatedRect> {
+		RotatedRect::new(self.center(), self.size(), 0.)
+	}
+}
+
 impl<T> From<(T, T, T, T)> for Rect_<T> {
 	#[inline]
 	fn from(s: (T, T, T, T)) -> Self {
@@ -131,7 +538,7 @@ impl<T> From<(Point_<T>, Size_<T>)> for Rect_<T> {
 	}
 }
 
-impl<T: PartialOrd + Sub<Output = T> + Copy> From<(Point_<T>, Point_<T>)> for Rect_<T> {
+impl<T: RectScalar> From<(Point_<T>, Point_<T>)> for Rect_<T> {
 	#[inline]
 	fn from(s: (Point_<T>, Point_<T>)) -> Self {
 		Self::from_points(s.0, s.1)
@@ -186,6 +593,18 @@ where
 	}
 }
 
+impl<T> Mul<T> for Rect_<T>
+where
+	Self: MulAssign<T>,
+{
+	type Output = Self;
+
+	fn mul(mut self, rhs: T) -> Self::Output {
+		self *= rhs;
+		self
+	}
+}
+
 impl<T> BitOr for Rect_<T>
 where
 	Rect_<T>: BitOrAssign,
@@ -238,7 +657,16 @@ impl<S, R: SubAssign<S>> SubAssign<Size_<S>> for Rect_<R> {
 	}
 }
 
-impl<T: PartialOrd + NumOps + Zero + Copy> BitOrAssign for Rect_<T> {
+impl<T: MulAssign + Copy> MulAssign<T> for Rect_<T> {
+	fn mul_assign(&mut self, rhs: T) {
+		self.x *= rhs;
+		self.y *= rhs;
+		self.width *= rhs;
+		self.height *= rhs;
+	}
+}
+
+impl<T: RectScalar> BitOrAssign for Rect_<T> {
 	fn bitor_assign(&mut self, rhs: Self) {
 		if self.empty() {
 			*self = rhs;
@@ -253,10 +681,7 @@ impl<T: PartialOrd + NumOps + Zero + Copy> BitOrAssign for Rect_<T> {
 	}
 }
 
-impl<T: PartialOrd + NumOps + Zero + Copy> BitAndAssign for Rect_<T>
-where
-	Self: Default,
-{
+impl<T: RectScalar> BitAndAssign for Rect_<T> {
 	fn bitand_assign(&mut self, rhs: Self) {
 		let x1 = partial_max(self.x, rhs.x);
 		let y1 = partial_max(self.y, rhs.y);
@@ -285,6 +710,105 @@ fn test_partial() {
 	assert_eq!(2, partial_max(1, 2));
 	assert_eq!(2, partial_max(2, 1));
 	assert_eq!(2, partial_max(2, 2));
+
+	assert!(partial_min(f64::NAN, 1.).is_nan());
+	assert!(partial_min(1., f64::NAN).is_nan());
+	assert!(partial_max(f32::NAN, 1.).is_nan());
+	assert!(partial_max(1., f32::NAN).is_nan());
+}
+
+/// Iterator over the integer points inside a `Rect_<i32>` in the row-major order, created by its `IntoIterator` impl
+#[derive(Clone, Debug)]
+pub struct RectPoints {
+	x_start: i32,
+	x_end: i32,
+	next: Point_<i32>,
+	remaining: usize,
+}
+
+impl RectPoints {
+	fn new(rect: Rect_<i32>) -> Self {
+		let remaining = if rect.empty() {
+			0
+		} else {
+			rect.width as usize * rect.height as usize
+		};
+		Self {
+			x_start: rect.x,
+			x_end: rect.x + rect.width,
+			next: rect.tl(),
+			remaining,
+		}
+	}
+}
+
+impl Iterator for RectPoints {
+	type Item = Point_<i32>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.remaining == 0 {
+			return None;
+		}
+		self.remaining -= 1;
+		let out = self.next;
+		self.next.x += 1;
+		if self.next.x == self.x_end {
+			self.next.x = self.x_start;
+			self.next.y += 1;
+		}
+		Some(out)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.remaining, Some(self.remaining))
+	}
+}
+
+impl ExactSizeIterator for RectPoints {}
+
+impl FusedIterator for RectPoints {}
+
+/// Iterate over every point from `(x, y)` to `(x + width - 1, y + height - 1)` in the row-major order
+impl IntoIterator for Rect_<i32> {
+	type Item = Point_<i32>;
+	type IntoIter = RectPoints;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		RectPoints::new(self)
+	}
+}
+
+impl IntoIterator for &Rect_<i32> {
+	type Item = Point_<i32>;
+	type IntoIter = RectPoints;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		RectPoints::new(*self)
+	}
+}
+
+/// Same format as `operator<<` of OpenCV: `[width x height from (x, y)]`
+impl<T: fmt::Display> fmt::Display for Rect_<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "[{} x {} from ({}, {})]", self.width, self.height, self.x, self.y)
+	}
+}
+
+impl<T: Copy> GeometryExtent for Rect_<T> {
+	type Scalar = T;
+
+	#[inline]
+	fn width(&self) -> T {
+		self.width
+	}
+
+	#[inline]
+	fn height(&self) -> T {
+		self.height
+	}
 }
 
 opencv_type_simple_generic! { Rect_<Copy> }
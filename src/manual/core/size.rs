@@ -1,13 +1,24 @@
+use std::fmt;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
-use num_traits::{NumCast, ToPrimitive, Zero};
+use num_traits::{CheckedMul, NumCast, ToPrimitive, Zero};
 
-use crate::core::Point_;
+use crate::core::{Point_, Rect_};
 use crate::opencv_type_simple_generic;
 
+/// Uniform access to the width and height of the 2D geometry types like [Size_] and [Rect_]
+pub trait GeometryExtent {
+	type Scalar;
+
+	fn width(&self) -> Self::Scalar;
+
+	fn height(&self) -> Self::Scalar;
+}
+
 /// [docs.opencv.org](https://docs.opencv.org/master/d6/d50/classcv_1_1Size__.html)
 #[repr(C)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Size_<T> {
 	pub width: T,
 	pub height: T,
@@ -19,6 +30,15 @@ impl<T> Size_<T> {
 		Self { width, height }
 	}
 
+	/// `Size` with both dimensions set to zero
+	#[inline]
+	pub fn zero() -> Self
+	where
+		T: Zero,
+	{
+		Self::new(T::zero(), T::zero())
+	}
+
 	#[inline]
 	pub fn from_point(pt: Point_<T>) -> Self {
 		Self {
@@ -27,6 +47,10 @@ impl<T> Size_<T> {
 		}
 	}
 
+	/// Product of the width and height
+	///
+	/// For the integer types the multiplication can overflow for large sizes: it panics in debug builds and wraps in
+	/// release ones. Use [Size_::checked_area] or [Size_::area_i64] when this is a concern.
 	#[inline]
 	pub fn area(self) -> T
 	where
@@ -35,6 +59,15 @@ impl<T> Size_<T> {
 		self.width * self.height
 	}
 
+	/// Product of the width and height, `None` if the multiplication overflows
+	#[inline]
+	pub fn checked_area(self) -> Option<T>
+	where
+		T: CheckedMul,
+	{
+		self.width.checked_mul(&self.height)
+	}
+
 	#[inline]
 	pub fn empty(self) -> bool
 	where
@@ -43,6 +76,55 @@ impl<T> Size_<T> {
 		self.width <= T::zero() || self.height <= T::zero()
 	}
 
+	/// `Rect` of this size with the top-left corner at (0, 0)
+	#[inline]
+	pub fn at_origin(self) -> Rect_<T>
+	where
+		T: Zero,
+	{
+		Rect_::new(T::zero(), T::zero(), self.width, self.height)
+	}
+
+	/// Aspect ratio of the `Size` as `width / height`
+	///
+	/// Follows the `f64` division rules for zero `height`: returns infinity (of the same sign as `width`) or NaN for
+	/// zero `width`.
+	#[inline]
+	pub fn aspect_ratio(&self) -> f64
+	where
+		T: ToPrimitive + Copy,
+	{
+		let to_f64 = |v: T| v.to_f64().unwrap_or(f64::NAN);
+		to_f64(self.width) / to_f64(self.height)
+	}
+
+	/// Scale the `Size` down preserving its aspect ratio so that it fits inside `bounds`
+	///
+	/// The result never exceeds either of the `bounds` dimensions. The `Size` is never scaled up, if it already fits
+	/// inside `bounds` it's returned unchanged. For integer types the scaled dimension is rounded down. Both `self`
+	/// and `bounds` are expected to have non-negative dimensions.
+	pub fn fit_within(&self, bounds: Size_<T>) -> Size_<T>
+	where
+		T: NumCast + PartialOrd + Copy,
+	{
+		if self.width <= bounds.width && self.height <= bounds.height {
+			return *self;
+		}
+		let to_f64 = |v: T| v.to_f64().unwrap_or(f64::NAN);
+		// `NumCast` truncates when converting to integers and the value is non-negative so it's rounded down
+		let scale = |v: T, ratio: f64, bound: T| match T::from(to_f64(v) * ratio) {
+			Some(scaled) if scaled <= bound => scaled,
+			_ => bound,
+		};
+		let width_ratio = to_f64(bounds.width) / to_f64(self.width);
+		let height_ratio = to_f64(bounds.height) / to_f64(self.height);
+		if width_ratio <= height_ratio {
+			Self::new(bounds.width, scale(self.height, width_ratio, bounds.height))
+		} else {
+			Self::new(scale(self.width, height_ratio, bounds.width), bounds.height)
+		}
+	}
+
 	/// Cast `Size` to the other coord type
 	#[inline]
 	pub fn to<D: NumCast>(self) -> Option<Size_<D>>
@@ -56,6 +138,35 @@ impl<T> Size_<T> {
 	}
 }
 
+impl Size_<i32> {
+	/// Product of the width and height widened to `i64`, it never overflows
+	#[inline]
+	pub fn area_i64(self) -> i64 {
+		self.width as i64 * self.height as i64
+	}
+
+	/// Aspect ratio of the `Size` as the width and height divided by their greatest common divisor, e.g. (16, 9) for 1920x1080
+	///
+	/// If one of the dimensions is zero the other one is reduced to 1 (or -1 if negative), (0, 0) stays as is.
+	pub fn aspect_ratio_reduced(self) -> (i32, i32) {
+		fn gcd(mut a: u32, mut b: u32) -> u32 {
+			while b != 0 {
+				(a, b) = (b, a % b);
+			}
+			a
+		}
+
+		match gcd(self.width.unsigned_abs(), self.height.unsigned_abs()) {
+			0 => (0, 0),
+			// gcd can only be outside of i32 range for (i32::MIN, i32::MIN) or (i32::MIN, 0)
+			divisor => match i32::try_from(divisor) {
+				Ok(divisor) => (self.width / divisor, self.height / divisor),
+				Err(_) => (self.width.signum(), self.height.signum()),
+			},
+		}
+	}
+}
+
 impl<T> From<(T, T)> for Size_<T> {
 	#[inline]
 	fn from(s: (T, T)) -> Self {
@@ -63,6 +174,27 @@ impl<T> From<(T, T)> for Size_<T> {
 	}
 }
 
+impl<T> From<[T; 2]> for Size_<T> {
+	#[inline]
+	fn from([width, height]: [T; 2]) -> Self {
+		Self::new(width, height)
+	}
+}
+
+impl<T> From<Size_<T>> for (T, T) {
+	#[inline]
+	fn from(s: Size_<T>) -> Self {
+		(s.width, s.height)
+	}
+}
+
+impl<T> From<Size_<T>> for [T; 2] {
+	#[inline]
+	fn from(s: Size_<T>) -> Self {
+		[s.width, s.height]
+	}
+}
+
 impl<T> From<Point_<T>> for Size_<T> {
 	#[inline]
 	fn from(s: Point_<T>) -> Self {
@@ -146,4 +278,25 @@ impl<T: DivAssign + Copy> DivAssign<T> for Size_<T> {
 	}
 }
 
+/// Same format as `operator<<` of OpenCV: `[width x height]`
+impl<T: fmt::Display> fmt::Display for Size_<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "[{} x {}]", self.width, self.height)
+	}
+}
+
+impl<T: Copy> GeometryExtent for Size_<T> {
+	type Scalar = T;
+
+	#[inline]
+	fn width(&self) -> T {
+		self.width
+	}
+
+	#[inline]
+	fn height(&self) -> T {
+		self.height
+	}
+}
+
 opencv_type_simple_generic! { Size_<Copy> }
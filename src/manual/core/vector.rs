@@ -151,6 +151,24 @@ where
 		Ok(())
 	}
 
+	/// Add all of the elements from `other` to the end of this `Vector`
+	///
+	/// Always returns `Ok`.
+	pub fn append(&mut self, other: &Vector<T>) -> Result<()> {
+		self.reserve(other.len());
+		for elem in other {
+			self.push_owned(elem);
+		}
+		Ok(())
+	}
+
+	/// Consume this `Vector` returning it with all of the elements from `other` added to the end
+	pub fn concat(mut self, other: &Vector<T>) -> Self {
+		// `append()` never fails
+		let _ = self.append(other);
+		self
+	}
+
 	/// Set element at the specified `index`
 	#[inline]
 	pub fn set(&mut self, index: size_t, val: <T as OpenCVType>::Arg) -> Result<()> {
@@ -344,6 +362,24 @@ where
 	}
 }
 
+impl<T: for<'o> OpenCVType<'o>> Vector<Vector<T>>
+where
+	Vector<T>: for<'o> OpenCVType<'o> + VectorExtern<T>,
+	Self: VectorExtern<Vector<T>>,
+{
+	/// Concatenate all inner vectors into a single `Vector`
+	pub fn flatten(&self) -> Result<Vector<T>> {
+		let mut out = Vector::new();
+		for inner in self {
+			out.reserve(inner.len());
+			for elem in inner {
+				out.push_owned(elem);
+			}
+		}
+		Ok(out)
+	}
+}
+
 impl<'b, T: Boxed> Vector<BoxedRef<'b, T>>
 where
 	BoxedRef<'b, T>: for<'o> OpenCVType<'o>,
@@ -0,0 +1,343 @@
+#[cfg(not(ocvrs_opencv_branch_4))]
+use crate::core::LINE_8;
+use crate::core::{
+	min_max_loc, no_array, normalize, Mat, Moments, Point, Point2d, Point2f, Point2i, Rect, Scalar, Size, TermCriteria,
+	ToInputOutputArray, Vec3f, Vec4i, Vector, BORDER_CONSTANT, CV_8U, NORM_MINMAX,
+};
+#[cfg(ocvrs_opencv_branch_4)]
+use crate::imgproc::LINE_8;
+use crate::imgproc::{
+	apply_color_map, circle, corner_sub_pix, good_features_to_track, grab_cut, hough_circles, hough_lines_p, integral2_def,
+	integral_def, match_template, moments, polylines, remap, watershed, ColormapTypes, InterpolationFlags, TemplateMatchModes,
+	GC_INIT_WITH_RECT, HOUGH_GRADIENT,
+};
+use crate::prelude::*;
+use crate::Result;
+
+/// Draw a circle with the same `radius`, `color` and `thickness` around every point of `centers`
+///
+/// Negative `thickness` means that a filled circle is drawn.
+pub fn draw_circles(
+	img: &mut impl ToInputOutputArray,
+	centers: &[Point2i],
+	radius: i32,
+	color: Scalar,
+	thickness: i32,
+) -> Result<()> {
+	for &center in centers {
+		circle(img, center, radius, color, thickness, LINE_8, 0)?;
+	}
+	Ok(())
+}
+
+/// Draw every polyline from `pts` with the same `color` and `thickness`
+///
+/// If `closed` is true then for every polyline a line is also drawn from its last vertex to its first one.
+pub fn draw_polylines(
+	img: &mut impl ToInputOutputArray,
+	pts: &[Vector<Point2i>],
+	closed: bool,
+	color: Scalar,
+	thickness: i32,
+) -> Result<()> {
+	for polyline in pts {
+		polylines(img, polyline, closed, color, thickness, LINE_8, 0)?;
+	}
+	Ok(())
+}
+
+/// Spatial moments `m_ji` of the image
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SpatialMoments {
+	pub m00: f64,
+	pub m10: f64,
+	pub m01: f64,
+	pub m20: f64,
+	pub m11: f64,
+	pub m02: f64,
+	pub m30: f64,
+	pub m21: f64,
+	pub m12: f64,
+	pub m03: f64,
+}
+
+/// Central moments `mu_ji` of the image, they are translation invariant
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CentralMoments {
+	pub mu20: f64,
+	pub mu11: f64,
+	pub mu02: f64,
+	pub mu30: f64,
+	pub mu21: f64,
+	pub mu12: f64,
+	pub mu03: f64,
+}
+
+/// Normalized central moments `nu_ji` of the image, they are translation and scale invariant
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct NormalizedMoments {
+	pub nu20: f64,
+	pub nu11: f64,
+	pub nu02: f64,
+	pub nu30: f64,
+	pub nu21: f64,
+	pub nu12: f64,
+	pub nu03: f64,
+}
+
+/// [Moments] of the image grouped by their kind, see [Mat::moments]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ImageMoments {
+	pub spatial: SpatialMoments,
+	pub central: CentralMoments,
+	pub normalized: NormalizedMoments,
+}
+
+impl ImageMoments {
+	/// Center of mass of the image calculated as `(m10 / m00, m01 / m00)`
+	///
+	/// The coordinates are NaN if the image has no mass (`m00` is zero).
+	#[inline]
+	pub fn centroid(&self) -> Point2d {
+		Point2d::new(self.spatial.m10 / self.spatial.m00, self.spatial.m01 / self.spatial.m00)
+	}
+}
+
+impl From<Moments> for ImageMoments {
+	fn from(m: Moments) -> Self {
+		Self {
+			spatial: SpatialMoments {
+				m00: m.m00,
+				m10: m.m10,
+				m01: m.m01,
+				m20: m.m20,
+				m11: m.m11,
+				m02: m.m02,
+				m30: m.m30,
+				m21: m.m21,
+				m12: m.m12,
+				m03: m.m03,
+			},
+			central: CentralMoments {
+				mu20: m.mu20,
+				mu11: m.mu11,
+				mu02: m.mu02,
+				mu30: m.mu30,
+				mu21: m.mu21,
+				mu12: m.mu12,
+				mu03: m.mu03,
+			},
+			normalized: NormalizedMoments {
+				nu20: m.nu20,
+				nu11: m.nu11,
+				nu02: m.nu02,
+				nu30: m.nu30,
+				nu21: m.nu21,
+				nu12: m.nu12,
+				nu03: m.nu03,
+			},
+		}
+	}
+}
+
+impl Mat {
+	/// Segment the foreground object inside `rect` of this 8-bit 3-channel image using the GrabCut algorithm
+	///
+	/// Returns an 8-bit single-channel mask of the same size as the image with 255 for the pixels classified as (probable)
+	/// foreground and 0 for the rest. Background and foreground models are managed internally.
+	pub fn grabcut(&self, rect: Rect, iterations: i32) -> Result<Mat> {
+		let mut mask = Mat::default();
+		let mut bgd_model = Mat::default();
+		let mut fgd_model = Mat::default();
+		grab_cut(
+			self,
+			&mut mask,
+			rect,
+			&mut bgd_model,
+			&mut fgd_model,
+			iterations,
+			GC_INIT_WITH_RECT,
+		)?;
+		// GC_FGD (1) and GC_PR_FGD (3) are the only classes with the lowest bit set
+		for class in mask.data_bytes_mut()? {
+			*class = if *class & 1 == 0 {
+				0
+			} else {
+				255
+			};
+		}
+		Ok(mask)
+	}
+
+	/// Find at most `max_corners` strongest corners in this 8-bit or 32-bit floating-point single-channel image
+	///
+	/// See [good_features_to_track] for the meaning of `quality` and `min_distance`. The rest of the parameters are set to
+	/// the OpenCV defaults: the whole image is searched (no mask), `block_size` is 3 and the minimal eigenvalue is used
+	/// instead of the Harris detector.
+	pub fn good_features(&self, max_corners: i32, quality: f64, min_distance: f64) -> Result<Vector<Point2f>> {
+		let mut corners = Vector::new();
+		good_features_to_track(
+			self,
+			&mut corners,
+			max_corners,
+			quality,
+			min_distance,
+			&Mat::default(),
+			3,
+			false,
+			0.04,
+		)?;
+		Ok(corners)
+	}
+
+	/// Refine the `corners` found in this single-channel image to the sub-pixel accuracy
+	///
+	/// Returns the refined copy of `corners` leaving the source intact, see [corner_sub_pix] for the meaning of `win_size`
+	/// and `criteria`. No zero zone in the middle of the search window is used.
+	pub fn refine_corners(&self, corners: &Vector<Point2f>, win_size: Size, criteria: TermCriteria) -> Result<Vector<Point2f>> {
+		let mut out = corners.clone();
+		corner_sub_pix(self, &mut out, win_size, Size::new(-1, -1), criteria)?;
+		Ok(out)
+	}
+
+	/// Slide `templ` over this image and compare the overlapped patches using `method`
+	///
+	/// Returns the 32-bit floating-point single-channel comparison result map, for a `W x H` image and a `w x h` template it
+	/// has the size of `(W - w + 1) x (H - h + 1)`.
+	pub fn match_template(&self, templ: &Mat, method: TemplateMatchModes) -> Result<Mat> {
+		let mut out = Mat::default();
+		match_template(self, templ, &mut out, method.into(), &no_array())?;
+		Ok(out)
+	}
+
+	/// Location of the top-left corner of the best match of `templ` inside this image using `method`
+	///
+	/// For [TemplateMatchModes::TM_SQDIFF] and [TemplateMatchModes::TM_SQDIFF_NORMED] the best match is the minimum of the
+	/// result map, for the rest of the methods it's the maximum.
+	pub fn best_match_location(&self, templ: &Mat, method: TemplateMatchModes) -> Result<Point> {
+		let res = self.match_template(templ, method)?;
+		let mut min_loc = Point::default();
+		let mut max_loc = Point::default();
+		min_max_loc(&res, None, None, Some(&mut min_loc), Some(&mut max_loc), &no_array())?;
+		Ok(match method {
+			TemplateMatchModes::TM_SQDIFF | TemplateMatchModes::TM_SQDIFF_NORMED => min_loc,
+			_ => max_loc,
+		})
+	}
+
+	/// Find line segments in this 8-bit single-channel binary image using the probabilistic Hough transform
+	///
+	/// `rho` and `theta` are the distance (in pixels) and angle (in radians) resolutions of the accumulator, only the lines
+	/// with more than `threshold` votes are returned. Every segment is returned as a pair of its end points, see
+	/// [hough_lines_p] to also set the minimum line length and the maximum gap between the line points.
+	pub fn hough_lines_p(&self, rho: f64, theta: f64, threshold: i32) -> Result<Vec<(Point2i, Point2i)>> {
+		let mut lines = Vector::<Vec4i>::new();
+		hough_lines_p(self, &mut lines, rho, theta, threshold, 0., 0.)?;
+		Ok(lines
+			.iter()
+			.map(|line| (Point2i::new(line[0], line[1]), Point2i::new(line[2], line[3])))
+			.collect())
+	}
+
+	/// Find circles in this 8-bit single-channel image using the Hough gradient method
+	///
+	/// See [hough_circles] for the meaning of the parameters. Every circle is returned as a pair of its center and radius.
+	pub fn hough_circles(
+		&self,
+		dp: f64,
+		min_dist: f64,
+		param1: f64,
+		param2: f64,
+		min_radius: i32,
+		max_radius: i32,
+	) -> Result<Vec<(Point2f, f32)>> {
+		let mut circles = Vector::<Vec3f>::new();
+		hough_circles(
+			self,
+			&mut circles,
+			HOUGH_GRADIENT,
+			dp,
+			min_dist,
+			param1,
+			param2,
+			min_radius,
+			max_radius,
+		)?;
+		Ok(circles
+			.iter()
+			.map(|circle| (Point2f::new(circle[0], circle[1]), circle[2]))
+			.collect())
+	}
+
+	/// Run the watershed segmentation of this 8-bit 3-channel image starting from the 32-bit single-channel `markers`
+	///
+	/// Returns the updated copy of `markers`, pixels on the region boundaries are set to -1.
+	pub fn watershed(&self, markers: &Mat) -> Result<Mat> {
+		let mut out = markers.try_clone()?;
+		watershed(self, &mut out)?;
+		Ok(out)
+	}
+
+	/// Calculate the moments of this single-channel image up to the third order
+	///
+	/// If `binary` is `true` all non-zero pixels are treated as 1.
+	#[inline]
+	pub fn moments(&self, binary: bool) -> Result<ImageMoments> {
+		moments(self, binary).map(ImageMoments::from)
+	}
+
+	/// Apply the generic geometrical transformation described by the precomputed `map1` and `map2` to this image
+	///
+	/// The maps are either `x` and `y` coordinates in separate `CV_32FC1` maps, or the representation produced by
+	/// [convert_maps](crate::imgproc::convert_maps). The pixels that are mapped from outside of the source image are set
+	/// to 0 (`BORDER_CONSTANT` with the zero border value), use [remap] to change that.
+	pub fn remap(&self, map1: &Mat, map2: &Mat, interpolation: InterpolationFlags) -> Result<Mat> {
+		let mut out = Mat::default();
+		remap(
+			self,
+			&mut out,
+			map1,
+			map2,
+			interpolation.into(),
+			BORDER_CONSTANT,
+			Scalar::default(),
+		)?;
+		Ok(out)
+	}
+
+	/// Calculate the integral image (summed area table) of this image
+	///
+	/// The result is 1 pixel wider and taller than the source, the depth is `CV_32S` for 8-bit sources and `CV_64F`
+	/// for the floating point ones.
+	pub fn integral(&self) -> Result<Mat> {
+		let mut sum = Mat::default();
+		integral_def(self, &mut sum)?;
+		Ok(sum)
+	}
+
+	/// Calculate the integral image and the integral of the squared pixel values of this image
+	///
+	/// Returns `(sum, sqsum)`, see [Mat::integral] for the `sum` details, `sqsum` is always `CV_64F`.
+	pub fn integral_sqsum(&self) -> Result<(Mat, Mat)> {
+		let mut sum = Mat::default();
+		let mut sqsum = Mat::default();
+		integral2_def(self, &mut sum, &mut sqsum)?;
+		Ok((sum, sqsum))
+	}
+
+	/// Apply the GNU Octave/MATLAB equivalent `colormap` to this single-channel or 3-channel image
+	///
+	/// Images with the depth other than `CV_8U` are first stretched to the full `0..=255` range (min-max normalization) and
+	/// converted to `CV_8U`. The result is an 8-bit 3-channel BGR image of the same size.
+	pub fn apply_colormap(&self, colormap: ColormapTypes) -> Result<Mat> {
+		let mut out = Mat::default();
+		if self.depth() == CV_8U {
+			apply_color_map(self, &mut out, colormap.into())?;
+		} else {
+			let mut src = Mat::default();
+			normalize(self, &mut src, 0., 255., NORM_MINMAX, CV_8U, &no_array())?;
+			apply_color_map(&src, &mut out, colormap.into())?;
+		}
+		Ok(out)
+	}
+}
@@ -1,9 +1,21 @@
+#[cfg(ocvrs_has_module_calib3d)]
+pub mod calib3d;
 #[cfg(ocvrs_has_module_core)]
 pub mod core;
 #[cfg(ocvrs_has_module_dnn)]
 pub mod dnn;
+#[cfg(ocvrs_has_module_imgproc)]
+pub mod imgproc;
+#[cfg(ocvrs_has_module_objdetect)]
+pub mod objdetect;
+#[cfg(ocvrs_has_module_photo)]
+pub mod photo;
 pub mod sys;
 pub mod types;
+#[cfg(ocvrs_has_module_video)]
+pub mod video;
+#[cfg(ocvrs_has_module_videoio)]
+pub mod videoio;
 
 pub mod prelude {
 	#[cfg(all(ocvrs_has_module_core, ocvrs_opencv_branch_32))]
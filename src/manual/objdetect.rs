@@ -0,0 +1,24 @@
+use crate::core::{Mat, Point2f, Vector};
+use crate::objdetect::QRCodeDetector;
+use crate::prelude::*;
+use crate::Result;
+
+/// Detect and decode all QR codes in `img`
+///
+/// Returns the decoded string of every detected code together with its 4 corner points. Codes that were detected, but
+/// couldn't be decoded have an empty string. The result is empty if there are no QR codes in the image.
+pub fn detect_qr_codes(img: &Mat) -> Result<Vec<(String, Vector<Point2f>)>> {
+	let detector = QRCodeDetector::default()?;
+	let mut decoded = Vector::<String>::new();
+	let mut points = Mat::default();
+	let mut straight_code = Vector::<Mat>::new();
+	if !detector.detect_and_decode_multi(img, &mut decoded, &mut points, &mut straight_code)? || points.empty() {
+		return Ok(vec![]);
+	}
+	let corners = points.data_typed::<Point2f>()?.chunks(4);
+	Ok(decoded
+		.iter()
+		.zip(corners)
+		.map(|(text, corners)| (text, Vector::from_slice(corners)))
+		.collect())
+}
@@ -0,0 +1,32 @@
+use crate::core::{Mat, Point, ToInputArray};
+use crate::photo::{MIXED_CLONE, MONOCHROME_TRANSFER, NORMAL_CLONE};
+use crate::Result;
+
+/// Typed version of the `flags` argument of [seamless_clone](crate::photo::seamless_clone)
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum SeamlessCloneMode {
+	/// Corresponds to [NORMAL_CLONE]
+	Normal = NORMAL_CLONE,
+	/// Corresponds to [MIXED_CLONE]
+	Mixed = MIXED_CLONE,
+	/// Corresponds to [MONOCHROME_TRANSFER]
+	MonochromeTransfer = MONOCHROME_TRANSFER,
+}
+
+/// Blend `src` into `dst` around `center` using Poisson image editing and return the result
+///
+/// Same as [seamless_clone](crate::photo::seamless_clone), but the output is returned as a new `Mat` of the same size as
+/// `dst` and the mode is typed.
+#[inline]
+pub fn seamless_clone_mat(
+	src: &impl ToInputArray,
+	dst: &impl ToInputArray,
+	mask: &impl ToInputArray,
+	center: Point,
+	mode: SeamlessCloneMode,
+) -> Result<Mat> {
+	let mut blend = Mat::default();
+	crate::photo::seamless_clone(src, dst, mask, center, &mut blend, mode as i32)?;
+	Ok(blend)
+}
@@ -0,0 +1,84 @@
+use crate::core::{Mat, Point, Point2f, Ptr, Vec2f, Vector};
+use crate::prelude::*;
+use crate::video::{
+	calc_optical_flow_farneback, calc_optical_flow_pyr_lk_def, create_background_subtractor_mog2, BackgroundSubtractorMOG2,
+};
+use crate::Result;
+
+/// Result of the sparse optical flow calculation by [Mat::optical_flow_pyr_lk]
+#[derive(Debug)]
+pub struct SparseFlowResult {
+	/// Calculated new positions of the input points in the next frame
+	pub next_points: Vector<Point2f>,
+	/// Whether the flow for the corresponding point has been found
+	pub status: Vec<bool>,
+	/// Tracking error for the corresponding point, not meaningful when `status` is `false`
+	pub errors: Vec<f32>,
+}
+
+impl Mat {
+	/// Compute the dense optical flow from this 8-bit single-channel frame to `next` using the Gunnar Farneback's algorithm
+	///
+	/// Returns the 32-bit floating-point 2-channel flow of the same size as the frames, use [flow_at] to read it. The
+	/// algorithm parameters are the ones commonly used in the OpenCV samples: `pyr_scale` 0.5, `levels` 3, `winsize` 15,
+	/// `iterations` 3, `poly_n` 5, `poly_sigma` 1.2 and no `flags`, use [calc_optical_flow_farneback] to tweak them.
+	pub fn optical_flow_farneback(&self, next: &Mat) -> Result<Mat> {
+		let mut flow = Mat::default();
+		calc_optical_flow_farneback(self, next, &mut flow, 0.5, 3, 15, 3, 5, 1.2, 0)?;
+		Ok(flow)
+	}
+
+	/// Track `prev_pts` from this 8-bit frame to `next` using the iterative Lucas-Kanade method with pyramids
+	///
+	/// Uses the default parameters of [calc_optical_flow_pyr_lk](crate::video::calc_optical_flow_pyr_lk), the per-point
+	/// status and error are collected into the returned [SparseFlowResult].
+	pub fn optical_flow_pyr_lk(&self, next: &Mat, prev_pts: &Vector<Point2f>) -> Result<SparseFlowResult> {
+		let mut next_points = Vector::<Point2f>::new();
+		let mut status = Vector::<u8>::new();
+		let mut errors = Vector::<f32>::new();
+		calc_optical_flow_pyr_lk_def(self, next, prev_pts, &mut next_points, &mut status, &mut errors)?;
+		Ok(SparseFlowResult {
+			next_points,
+			status: status.iter().map(|s| s != 0).collect(),
+			errors: errors.to_vec(),
+		})
+	}
+}
+
+/// Read the flow vector at `pt` from the `flow` produced by [Mat::optical_flow_farneback]
+///
+/// The `x` and `y` of the result are the horizontal and vertical displacement of the pixel between the frames.
+#[inline]
+pub fn flow_at(flow: &Mat, pt: Point) -> Result<Point2f> {
+	let v = flow.at_2d::<Vec2f>(pt.y, pt.x)?;
+	Ok(Point2f::new(v[0], v[1]))
+}
+
+/// Foreground detection using the Gaussian mixture based background subtraction
+///
+/// Owns the underlying [BackgroundSubtractorMOG2] so that the model state is preserved between calls to
+/// [SimpleBackgroundSubtractor::apply].
+#[derive(Debug)]
+pub struct SimpleBackgroundSubtractor {
+	inner: Ptr<BackgroundSubtractorMOG2>,
+}
+
+impl SimpleBackgroundSubtractor {
+	/// Create a new subtractor, `history` is the number of the last frames affecting the background model and `threshold`
+	/// is the squared Mahalanobis distance for a pixel to be considered background, shadows are not detected
+	pub fn new(history: i32, threshold: f64) -> Result<Self> {
+		Ok(Self {
+			inner: create_background_subtractor_mog2(history, threshold, false)?,
+		})
+	}
+
+	/// Update the background model with `frame` and return its 8-bit foreground mask
+	///
+	/// Foreground pixels are set to 255 and background pixels to 0 in the result. The learning rate is chosen
+	/// automatically.
+	pub fn apply(&mut self, frame: &Mat) -> Result<Mat> {
+		let mut mask = Mat::default();
+		self.inner.apply(frame, &mut mask, -1.)?;
+		Ok(mask)
+	}
+}
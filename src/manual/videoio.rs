@@ -0,0 +1,33 @@
+use std::iter;
+
+use crate::core::Mat;
+use crate::prelude::*;
+use crate::videoio::VideoCapture;
+use crate::Result;
+
+impl VideoCapture {
+	/// Iterate over the frames of the stream until it ends
+	///
+	/// The iteration stops when [read](VideoCaptureTrait::read) returns `false` or an empty frame, which is how the end of
+	/// the stream is signaled. An error is yielded once and then the iteration stops too.
+	pub fn frames(&mut self) -> impl Iterator<Item = Result<Mat>> + '_ {
+		let mut done = false;
+		iter::from_fn(move || {
+			if done {
+				return None;
+			}
+			let mut frame = Mat::default();
+			match self.read(&mut frame) {
+				Ok(true) if !frame.empty() => Some(Ok(frame)),
+				Ok(_) => {
+					done = true;
+					None
+				}
+				Err(e) => {
+					done = true;
+					Some(Err(e))
+				}
+			}
+		})
+	}
+}
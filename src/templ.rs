@@ -57,6 +57,21 @@ macro_rules! userdata_arg {
 	};
 }
 
+macro_rules! closure_arg {
+	($tr_name: ident($($tr_arg_name: ident: $tr_arg_type: ty => $fw_arg_type: ty),*) => $tr_userdata_name: ident => $closure_name: ident) => {
+		unsafe extern "C" fn trampoline($($tr_arg_name: $tr_arg_type,)* userdata: *mut ::std::ffi::c_void) {
+			let closure = &*userdata.cast::<&(dyn Fn($($fw_arg_type),*) + Sync)>();
+			closure($(<$fw_arg_type as $crate::traits::OpenCVType>::opencv_from_extern($tr_arg_name)),*);
+		}
+
+		let $tr_name = trampoline as unsafe extern "C" fn($($tr_arg_type,)* *mut ::std::ffi::c_void);
+		// the closure is borrowed for the duration of the call, so it's enough to pass the pointer to the reference
+		let $tr_userdata_name = (&$closure_name as *const &(dyn Fn($($fw_arg_type),*) + Sync))
+			.cast_mut()
+			.cast::<::std::ffi::c_void>();
+	};
+}
+
 macro_rules! input_array_arg {
 	($name: ident) => {
 		let $name = $name.input_array()?;
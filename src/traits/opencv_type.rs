@@ -158,9 +158,12 @@ macro_rules! opencv_type_copy {
 #[macro_export]
 macro_rules! opencv_type_enum {
 	($type: ty) => {
+		$crate::opencv_type_enum! { $type, i32 }
+	};
+	($type: ty, $int_type: ty) => {
 		$crate::opencv_type_copy! { $type }
 
-		impl From<$type> for i32 {
+		impl From<$type> for $int_type {
 			#[inline]
 			fn from(v: $type) -> Self {
 				v as Self
@@ -169,6 +172,93 @@ macro_rules! opencv_type_enum {
 	};
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! opencv_type_enum_flags {
+	($flags: ident, $type: ty, $int_type: ty) => {
+		impl $flags {
+			/// No flags set
+			#[inline]
+			pub const fn empty() -> Self {
+				Self(0)
+			}
+
+			/// Raw value to pass to OpenCV
+			#[inline]
+			pub const fn bits(self) -> $int_type {
+				self.0
+			}
+
+			#[inline]
+			pub const fn is_empty(self) -> bool {
+				self.0 == 0
+			}
+
+			/// `true` if all of the bits of `other` are also set in `self`
+			#[inline]
+			pub fn contains(self, other: impl Into<Self>) -> bool {
+				let other = other.into().0;
+				self.0 & other == other
+			}
+		}
+
+		impl From<$type> for $flags {
+			#[inline]
+			fn from(v: $type) -> Self {
+				Self(v as $int_type)
+			}
+		}
+
+		impl From<$int_type> for $flags {
+			#[inline]
+			fn from(v: $int_type) -> Self {
+				Self(v)
+			}
+		}
+
+		impl From<$flags> for $int_type {
+			#[inline]
+			fn from(v: $flags) -> Self {
+				v.0
+			}
+		}
+
+		impl<T: Into<$flags>> ::std::ops::BitOr<T> for $flags {
+			type Output = Self;
+
+			#[inline]
+			fn bitor(self, rhs: T) -> Self {
+				Self(self.0 | rhs.into().0)
+			}
+		}
+
+		impl<T: Into<$flags>> ::std::ops::BitOr<T> for $type {
+			type Output = $flags;
+
+			#[inline]
+			fn bitor(self, rhs: T) -> $flags {
+				$flags::from(self) | rhs
+			}
+		}
+
+		impl<T: Into<$flags>> ::std::ops::BitOrAssign<T> for $flags {
+			#[inline]
+			fn bitor_assign(&mut self, rhs: T) {
+				self.0 |= rhs.into().0;
+			}
+		}
+
+		impl<T: Into<$flags>> ::std::ops::BitAnd<T> for $flags {
+			type Output = Self;
+
+			#[inline]
+			fn bitand(self, rhs: T) -> Self {
+				Self(self.0 & rhs.into().0)
+			}
+		}
+	};
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! opencv_type_simple {
@@ -177,3 +177,64 @@ fn implicit_clone() -> Result<()> {
 	assert_eq!(10, key_point_clone.octave());
 	Ok(())
 }
+
+/// Generated Debug impls only print the pointer so they never call into OpenCV
+#[test]
+fn debug() -> Result<()> {
+	let key_point = KeyPoint::new_coords(1., 2., 3., 4., 5., 6, 7)?;
+	assert_eq!(
+		format!("KeyPoint {{ ptr: {:?} }}", key_point.as_raw()),
+		format!("{key_point:?}")
+	);
+	let empty = unsafe { KeyPoint::from_raw(std::ptr::null_mut()) };
+	assert_eq!(
+		format!("KeyPoint {{ ptr: {:?} }}", std::ptr::null::<c_void>()),
+		format!("{empty:?}")
+	);
+	std::mem::forget(empty);
+	Ok(())
+}
+
+/// Methods of classes in `NULL_RECEIVER_CHECK` return an error instead of passing a null pointer to OpenCV
+#[test]
+fn null_receiver() {
+	use opencv::{core, Error};
+
+	let alg = unsafe { Algorithm::from_raw(std::ptr::null_mut()) };
+	assert!(matches!(
+		alg.empty(),
+		Err(Error {
+			code: core::StsNullPtr,
+			..
+		})
+	));
+	assert!(matches!(
+		alg.get_default_name(),
+		Err(Error {
+			code: core::StsNullPtr,
+			..
+		})
+	));
+	std::mem::forget(alg);
+}
+
+#[test]
+fn derived_as_base() -> Result<()> {
+	#![cfg(ocvrs_has_module_features2d)]
+	use opencv::core::Ptr;
+	use opencv::features2d::{Feature2D, ORB};
+
+	fn default_name(detector: &impl Feature2DTraitConst) -> Result<String> {
+		detector.get_default_name()
+	}
+
+	fn base_default_name(detector: &Ptr<Feature2D>) -> Result<String> {
+		default_name(detector)
+	}
+
+	let orb = ORB::create_def()?;
+	assert_eq!("Feature2D.ORB", default_name(&orb)?);
+	let base: Ptr<Feature2D> = orb.into();
+	assert_eq!("Feature2D.ORB", base_default_name(&base)?);
+	Ok(())
+}
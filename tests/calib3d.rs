@@ -0,0 +1,70 @@
+#![cfg(ocvrs_has_module_calib3d)]
+
+use opencv::calib3d::{solve_pnp_pose, SgbmParams, StereoDisparity};
+use opencv::core::{Point2f, Point3f, Rect, Scalar, Vector, NORM_INF};
+use opencv::prelude::*;
+use opencv::{core, Result};
+
+#[test]
+fn solve_pnp_planar() -> Result<()> {
+	// square of side 2 at z = 0 viewed from 10 units away with no rotation
+	let object_points = Vector::<Point3f>::from_iter([
+		Point3f::new(-1., -1., 0.),
+		Point3f::new(1., -1., 0.),
+		Point3f::new(1., 1., 0.),
+		Point3f::new(-1., 1., 0.),
+	]);
+	let image_points = Vector::<Point2f>::from_iter([
+		Point2f::new(240., 160.),
+		Point2f::new(400., 160.),
+		Point2f::new(400., 320.),
+		Point2f::new(240., 320.),
+	]);
+	let camera_matrix = Mat::from_slice_2d(&[[800., 0., 320.], [0., 800., 240.], [0., 0., 1.]])?;
+	let dist_coeffs = Mat::default();
+	let pose = solve_pnp_pose(&object_points, &image_points, &camera_matrix, &dist_coeffs)?;
+	assert!(pose.success);
+	assert!(pose.rvec.norm() < 1e-3);
+	assert!(pose.tvec.x.abs() < 1e-3);
+	assert!(pose.tvec.y.abs() < 1e-3);
+	assert!((pose.tvec.z - 10.).abs() < 1e-3);
+	Ok(())
+}
+
+#[test]
+fn stereo_disparity() -> Result<()> {
+	let mut scene = Mat::new_rows_cols_with_default(64, 104, u8::opencv_type(), Scalar::all(0.))?;
+	core::randu(&mut scene, &Scalar::all(0.), &Scalar::all(255.))?;
+	// the scene is shifted 8 pixels to the left in the right image
+	let left = scene.roi(Rect::new(0, 0, 96, 64))?.try_clone()?;
+	let right = scene.roi(Rect::new(8, 0, 96, 64))?.try_clone()?;
+
+	for mut matcher in [
+		StereoDisparity::sgbm(SgbmParams {
+			block_size: 5,
+			..SgbmParams::default()
+		})?,
+		StereoDisparity::bm(16, 9)?,
+	] {
+		let disparity = matcher.compute(&left, &right)?;
+		assert_eq!(left.size()?, disparity.size()?);
+		assert_eq!(i16::opencv_type(), disparity.typ());
+		let center = *disparity.at_2d::<i16>(32, 48)?;
+		assert!((7 * 16..=9 * 16).contains(&center), "Unexpected disparity: {center}");
+	}
+	Ok(())
+}
+
+#[test]
+fn fisheye_undistort() -> Result<()> {
+	let mut img = Mat::new_rows_cols_with_default(48, 64, u8::opencv_type(), Scalar::all(0.))?;
+	core::randu(&mut img, &Scalar::all(0.), &Scalar::all(255.))?;
+	// with the very long focal length and no distortion the fisheye projection is practically the pinhole one
+	let camera_matrix = Mat::from_slice_2d(&[[10_000., 0., 31.5], [0., 10_000., 23.5], [0., 0., 1.]])?;
+	let dist_coeffs = Mat::from_slice(&[0f64; 4])?.try_clone()?;
+	let undistorted = img.fisheye_undistort(&camera_matrix, &dist_coeffs)?;
+	assert_eq!(img.size()?, undistorted.size()?);
+	assert_eq!(img.typ(), undistorted.typ());
+	assert!(core::norm2(&img, &undistorted, NORM_INF, &core::no_array())? <= 1.);
+	Ok(())
+}
@@ -1,4 +1,8 @@
-use opencv::core::{Moments, Point2f, RotatedRect, Scalar, Size2f, Vec3b, Vector, CV_32S, CV_64F, CV_8U, CV_MAKETYPE};
+use std::sync::atomic::{AtomicI32, Ordering};
+
+use opencv::core::{
+	Moments, Point2f, Range, Rect2f, RotatedRect, Scalar, Size2f, Vec3b, Vector, CV_32S, CV_64F, CV_8U, CV_MAKETYPE,
+};
 use opencv::prelude::*;
 use opencv::{core, Result};
 
@@ -38,6 +42,25 @@ fn rotated_rect() -> Result<()> {
 	Ok(())
 }
 
+#[test]
+fn rect_to_rotated_rect() -> Result<()> {
+	let rect = Rect2f::new(10., 20., 30., 40.);
+	let rotated = rect.to_rotated_rect()?;
+	assert_eq!(Point2f::new(25., 40.), rotated.center);
+	assert_eq!(Size2f::new(30., 40.), rotated.size);
+	assert_eq!(0., rotated.angle);
+	assert_eq!(rect, rotated.bounding_rect2f()?);
+
+	let half_diagonal = 10. * 2f32.sqrt() / 2.;
+	let rotated = RotatedRect::new(Point2f::new(0., 0.), Size2f::new(10., 10.), 45.)?;
+	let bounds = rotated.bounding_rect2f()?;
+	assert!((bounds.x + half_diagonal).abs() < 1e-4);
+	assert!((bounds.y + half_diagonal).abs() < 1e-4);
+	assert!((bounds.width - 2. * half_diagonal).abs() < 1e-4);
+	assert!((bounds.height - 2. * half_diagonal).abs() < 1e-4);
+	Ok(())
+}
+
 #[test]
 fn in_range() -> Result<()> {
 	let mut cs = Vector::<Mat>::new();
@@ -99,3 +122,20 @@ fn min_max_loc() -> Result<()> {
 	assert_eq!(30., max_val);
 	Ok(())
 }
+
+/// The closure passed as `std::function` is called for the whole range (possibly from several threads) before returning
+#[test]
+fn parallel_for_closure() -> Result<()> {
+	let sum = AtomicI32::new(0);
+	core::parallel_for_closure(
+		&Range::new(0, 100)?,
+		&|range| {
+			for i in range.start()..range.end() {
+				sum.fetch_add(i, Ordering::Relaxed);
+			}
+		},
+		-1.,
+	)?;
+	assert_eq!(4950, sum.load(Ordering::Relaxed));
+	Ok(())
+}
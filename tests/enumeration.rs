@@ -23,3 +23,27 @@ fn enum_into() -> Result<()> {
 	assert_eq!(3, BorderTypes::BORDER_WRAP.into());
 	Ok(())
 }
+
+#[test]
+fn enum_all() -> Result<()> {
+	assert_eq!(BorderTypes::BORDER_CONSTANT, BorderTypes::ALL[0]);
+	assert_eq!(Some(&BorderTypes::BORDER_ISOLATED), BorderTypes::ALL.last());
+	// duplicate values like BORDER_DEFAULT are not listed
+	assert_eq!(7, BorderTypes::ALL.len());
+	for v in BorderTypes::ALL {
+		assert_eq!(*v, BorderTypes::try_from(i32::from(*v))?);
+	}
+	Ok(())
+}
+
+#[test]
+fn enum_from_i32() {
+	assert_eq!(Some(BorderTypes::BORDER_REFLECT), BorderTypes::from_i32(2));
+	// duplicate value resolves to the first declared variant
+	assert_eq!(
+		Some(BorderTypes::BORDER_REFLECT_101),
+		BorderTypes::from_i32(opencv::core::BORDER_DEFAULT)
+	);
+	assert_eq!(None, BorderTypes::from_i32(10));
+	assert_eq!(None, BorderTypes::from_i32(-1));
+}
@@ -37,3 +37,20 @@ fn decode() -> Result<()> {
 
 	Ok(())
 }
+
+#[test]
+fn decode_flags() -> Result<()> {
+	use imgcodecs::{ImreadModes, ImreadModesFlags};
+
+	let src = Mat::from_slice::<u8>(PIXEL)?;
+	let flags = ImreadModes::IMREAD_GRAYSCALE | ImreadModes::IMREAD_IGNORE_ORIENTATION;
+	assert!(flags.contains(ImreadModes::IMREAD_IGNORE_ORIENTATION));
+	let dest = imgcodecs::imdecode(&src, flags)?;
+	assert_eq!(dest.size()?, Size::new(1, 1));
+	assert_eq!(dest.channels(), 1);
+
+	let dest = imgcodecs::imdecode(&src, ImreadModesFlags::from(ImreadModes::IMREAD_COLOR))?;
+	assert_eq!(dest.channels(), 3);
+	assert_eq!(*dest.at_2d::<Vec3b>(0, 0)?, Vec3b::from([56u8, 56, 191]));
+	Ok(())
+}
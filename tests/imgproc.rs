@@ -2,7 +2,10 @@
 
 use std::ffi::c_void;
 
-use opencv::core::{Point, Point2f, Rect, RotatedRect, Size, Size2f, Vec2f, Vec3b, Vector};
+use opencv::core::{
+	Point, Point2d, Point2f, Rect, RotatedRect, Scalar, Size, Size2f, TermCriteria, TermCriteria_Type, Vec2f, Vec3b, Vector,
+};
+use opencv::imgproc::ColormapTypes;
 use opencv::prelude::*;
 use opencv::{imgproc, Result};
 
@@ -92,6 +95,176 @@ fn call_def() -> Result<()> {
 	Ok(())
 }
 
+#[test]
+fn draw_circles() -> Result<()> {
+	let red = Vec3b::from([0, 0, 255]);
+	let black = Vec3b::all(0);
+	let mut img = Mat::new_rows_cols_with_default(100, 100, Vec3b::opencv_type(), Scalar::all(0.))?;
+	imgproc::draw_circles(&mut img, &[Point::new(20, 20), Point::new(70, 60)], 5, (0, 0, 255).into(), -1)?;
+	assert_eq!(red, *img.at_2d::<Vec3b>(20, 20)?);
+	assert_eq!(red, *img.at_2d::<Vec3b>(60, 70)?);
+	assert_eq!(red, *img.at_2d::<Vec3b>(63, 70)?);
+	assert_eq!(black, *img.at_2d::<Vec3b>(40, 40)?);
+	assert_eq!(black, *img.at_2d::<Vec3b>(20, 70)?);
+	Ok(())
+}
+
+#[test]
+fn draw_polylines() -> Result<()> {
+	let green = Vec3b::from([0, 255, 0]);
+	let black = Vec3b::all(0);
+	let square = Vector::<Point>::from_iter([Point::new(50, 50), Point::new(90, 50), Point::new(90, 90), Point::new(50, 90)]);
+	let segment = Vector::<Point>::from_iter([Point::new(10, 10), Point::new(10, 40)]);
+	let polylines = [square, segment];
+
+	let mut img = Mat::new_rows_cols_with_default(100, 100, Vec3b::opencv_type(), Scalar::all(0.))?;
+	imgproc::draw_polylines(&mut img, &polylines, false, (0, 255, 0).into(), 1)?;
+	assert_eq!(green, *img.at_2d::<Vec3b>(50, 70)?);
+	assert_eq!(green, *img.at_2d::<Vec3b>(70, 90)?);
+	assert_eq!(green, *img.at_2d::<Vec3b>(25, 10)?);
+	assert_eq!(black, *img.at_2d::<Vec3b>(70, 50)?);
+	assert_eq!(black, *img.at_2d::<Vec3b>(70, 70)?);
+
+	imgproc::draw_polylines(&mut img, &polylines, true, (0, 255, 0).into(), 1)?;
+	assert_eq!(green, *img.at_2d::<Vec3b>(70, 50)?);
+	assert_eq!(black, *img.at_2d::<Vec3b>(70, 70)?);
+	Ok(())
+}
+
+#[test]
+fn grabcut() -> Result<()> {
+	let mut img = Mat::new_rows_cols_with_default(64, 64, Vec3b::opencv_type(), Scalar::all(0.))?;
+	img.roi_mut(Rect::new(20, 20, 24, 24))?.set_to_def(&Scalar::all(255.))?;
+	let mask = img.grabcut(Rect::new(10, 10, 44, 44), 3)?;
+	assert_eq!(img.size()?, mask.size()?);
+	assert_eq!(u8::opencv_type(), mask.typ());
+	assert!(mask.data_bytes()?.iter().all(|&v| v == 0 || v == 255));
+	assert_eq!(255, *mask.at_2d::<u8>(32, 32)?);
+	assert_eq!(0, *mask.at_2d::<u8>(2, 2)?);
+	assert_eq!(0, *mask.at_2d::<u8>(60, 60)?);
+	Ok(())
+}
+
+#[test]
+fn good_features() -> Result<()> {
+	let mut img = Mat::new_rows_cols_with_default(80, 80, u8::opencv_type(), Scalar::all(0.))?;
+	for (row, col) in [(20, 20), (40, 40)] {
+		img.roi_mut(Rect::new(col, row, 20, 20))?.set_to_def(&Scalar::all(255.))?;
+	}
+	let corners = img.good_features(10, 0.01, 5.)?;
+	assert!(!corners.is_empty());
+	assert!(corners.len() <= 10);
+	for expected in [Point2f::new(20., 20.), Point2f::new(60., 60.)] {
+		assert!(
+			corners.iter().any(|corner| (corner - expected).norm() <= 2.),
+			"No corner found near {expected:?} in {corners:?}"
+		);
+	}
+	assert!(img.good_features(1, 0.01, 5.)?.len() <= 1);
+	Ok(())
+}
+
+#[test]
+fn refine_corners() -> Result<()> {
+	let mut img = Mat::new_rows_cols_with_default(60, 60, u8::opencv_type(), Scalar::all(0.))?;
+	img.roi_mut(Rect::new(20, 20, 20, 20))?.set_to_def(&Scalar::all(255.))?;
+	let mut blurred = Mat::default();
+	imgproc::gaussian_blur_def(&img, &mut blurred, Size::new(5, 5), 1.5)?;
+
+	// the corner of the square lies on the pixel boundary
+	let expected = Point2f::new(19.5, 19.5);
+	let corners = Vector::from_slice(&[Point2f::new(22., 21.)]);
+	let criteria = TermCriteria::new(TermCriteria_Type::COUNT as i32 | TermCriteria_Type::EPS as i32, 40, 0.001)?;
+	let refined = blurred.refine_corners(&corners, Size::new(5, 5), criteria)?;
+	assert_eq!(1, refined.len());
+	assert_eq!(Point2f::new(22., 21.), corners.get(0)?);
+	let refined_pt = refined.get(0)?;
+	let refined_dist = (refined_pt - expected).norm();
+	assert!(refined_dist < (corners.get(0)? - expected).norm());
+	assert!(
+		refined_dist < 0.5,
+		"Refined corner {refined_pt:?} is too far from {expected:?}"
+	);
+	Ok(())
+}
+
+#[test]
+fn match_template() -> Result<()> {
+	let mut img = Mat::new_rows_cols_with_default(60, 80, u8::opencv_type(), Scalar::all(0.))?;
+	for (i, v) in img.data_bytes_mut()?.iter_mut().enumerate() {
+		*v = (i * 7 % 251) as u8;
+	}
+	let templ_rect = Rect::new(37, 21, 12, 9);
+	let templ = img.roi(templ_rect)?.try_clone()?;
+
+	let res = img.match_template(&templ, imgproc::TemplateMatchModes::TM_SQDIFF)?;
+	assert_eq!(Size::new(80 - 12 + 1, 60 - 9 + 1), res.size()?);
+	assert_eq!(f32::opencv_type(), res.typ());
+	assert!(res.at_2d::<f32>(templ_rect.y, templ_rect.x)?.abs() < 1.);
+
+	for method in [
+		imgproc::TemplateMatchModes::TM_SQDIFF,
+		imgproc::TemplateMatchModes::TM_SQDIFF_NORMED,
+		imgproc::TemplateMatchModes::TM_CCORR_NORMED,
+		imgproc::TemplateMatchModes::TM_CCOEFF,
+		imgproc::TemplateMatchModes::TM_CCOEFF_NORMED,
+	] {
+		assert_eq!(
+			templ_rect.tl(),
+			img.best_match_location(&templ, method)?,
+			"Method: {method:?}"
+		);
+	}
+	Ok(())
+}
+
+#[test]
+fn hough_lines_p() -> Result<()> {
+	let mut img = Mat::new_rows_cols_with_default(100, 100, u8::opencv_type(), Scalar::all(0.))?;
+	img.roi_mut(Rect::new(10, 50, 80, 1))?.set_to_def(&Scalar::all(255.))?;
+	let lines = img.hough_lines_p(1., std::f64::consts::PI / 180., 50)?;
+	assert!(!lines.is_empty());
+	for (start, end) in lines {
+		assert_eq!(50, start.y);
+		assert_eq!(50, end.y);
+		assert!(start.x.min(end.x) >= 10);
+		assert!(start.x.max(end.x) <= 89);
+	}
+	assert!(Mat::new_rows_cols_with_default(100, 100, u8::opencv_type(), Scalar::all(0.))?
+		.hough_lines_p(1., std::f64::consts::PI / 180., 50)?
+		.is_empty());
+	Ok(())
+}
+
+#[test]
+fn hough_circles() -> Result<()> {
+	let mut img = Mat::new_rows_cols_with_default(200, 200, u8::opencv_type(), Scalar::all(0.))?;
+	imgproc::draw_circles(&mut img, &[Point::new(100, 90)], 40, Scalar::all(255.), -1)?;
+	let circles = img.hough_circles(1., 50., 100., 20., 20, 60)?;
+	assert_eq!(1, circles.len());
+	let (center, radius) = circles[0];
+	assert!(
+		(center - Point2f::new(100., 90.)).norm() < 3.,
+		"Unexpected center: {center:?}"
+	);
+	assert!((radius - 40.).abs() < 3., "Unexpected radius: {radius}");
+	Ok(())
+}
+
+#[test]
+fn watershed() -> Result<()> {
+	let img = Mat::new_rows_cols_with_default(32, 32, Vec3b::opencv_type(), Scalar::all(0.))?;
+	let mut markers = Mat::new_rows_cols_with_default(32, 32, i32::opencv_type(), Scalar::all(0.))?;
+	*markers.at_2d_mut::<i32>(4, 4)? = 1;
+	*markers.at_2d_mut::<i32>(28, 28)? = 2;
+	let res = img.watershed(&markers)?;
+	assert_eq!(0, *markers.at_2d::<i32>(16, 16)?);
+	assert_eq!(1, *res.at_2d::<i32>(4, 4)?);
+	assert_eq!(2, *res.at_2d::<i32>(28, 28)?);
+	assert!(res.data_typed::<i32>()?.iter().all(|&v| v == -1 || v == 1 || v == 2));
+	Ok(())
+}
+
 #[test]
 fn box_points() -> Result<()> {
 	let rect = RotatedRect::new(Point2f::new(100., 100.), Size2f::new(100., 100.), 90.)?;
@@ -108,3 +281,90 @@ fn box_points() -> Result<()> {
 
 	Ok(())
 }
+
+#[test]
+fn remap() -> Result<()> {
+	let mut src = Mat::new_rows_cols_with_default(20, 30, u8::opencv_type(), Scalar::all(0.))?;
+	for (i, v) in src.data_bytes_mut()?.iter_mut().enumerate() {
+		*v = (i % 251) as u8;
+	}
+	let mut map_x = Mat::new_rows_cols_with_default(20, 30, f32::opencv_type(), Scalar::all(0.))?;
+	let mut map_y = Mat::new_rows_cols_with_default(20, 30, f32::opencv_type(), Scalar::all(0.))?;
+	for row in 0..20 {
+		for col in 0..30 {
+			*map_x.at_2d_mut::<f32>(row, col)? = col as f32;
+			*map_y.at_2d_mut::<f32>(row, col)? = row as f32;
+		}
+	}
+	for interpolation in [
+		imgproc::InterpolationFlags::INTER_NEAREST,
+		imgproc::InterpolationFlags::INTER_LINEAR,
+	] {
+		let out = src.remap(&map_x, &map_y, interpolation)?;
+		assert_eq!(src.size()?, out.size()?);
+		assert_eq!(src.data_bytes()?, out.data_bytes()?);
+	}
+
+	// shift to the right by 5 pixels, the uncovered area is filled with zeros
+	let mut shifted_x = map_x.try_clone()?;
+	for v in shifted_x.data_typed_mut::<f32>()? {
+		*v -= 5.;
+	}
+	let out = src.remap(&shifted_x, &map_y, imgproc::InterpolationFlags::INTER_NEAREST)?;
+	assert_eq!(0, *out.at_2d::<u8>(10, 2)?);
+	assert_eq!(*src.at_2d::<u8>(10, 10)?, *out.at_2d::<u8>(10, 15)?);
+	Ok(())
+}
+
+#[test]
+fn moments() -> Result<()> {
+	let mut img = Mat::new_rows_cols_with_default(100, 100, u8::opencv_type(), Scalar::all(0.))?;
+	img.roi_mut(Rect::new(30, 40, 21, 11))?.set_to_def(&Scalar::all(200.))?;
+
+	let binary = img.moments(true)?;
+	assert_eq!(21. * 11., binary.spatial.m00);
+	assert_eq!(Point2d::new(40., 45.), binary.centroid());
+	assert!(binary.central.mu11.abs() < 1e-6);
+	assert!(binary.normalized.nu20 > binary.normalized.nu02);
+
+	let grayscale = img.moments(false)?;
+	assert_eq!(21. * 11. * 200., grayscale.spatial.m00);
+	assert_eq!(binary.centroid(), grayscale.centroid());
+
+	let empty = Mat::new_rows_cols_with_default(10, 10, u8::opencv_type(), Scalar::all(0.))?.moments(true)?;
+	assert!(empty.centroid().x.is_nan());
+	Ok(())
+}
+
+#[test]
+fn integral() -> Result<()> {
+	let img = Mat::new_rows_cols_with_default(4, 5, u8::opencv_type(), Scalar::all(3.))?;
+
+	let sum = img.integral()?;
+	assert_eq!(Size::new(6, 5), sum.size()?);
+	assert_eq!(i32::opencv_type(), sum.typ());
+	assert_eq!(0, *sum.at_2d::<i32>(0, 0)?);
+	assert_eq!(3 * 4 * 5, *sum.at_2d::<i32>(4, 5)?);
+
+	let (sum, sqsum) = img.integral_sqsum()?;
+	assert_eq!(3 * 4 * 5, *sum.at_2d::<i32>(4, 5)?);
+	assert_eq!(9. * 4. * 5., *sqsum.at_2d::<f64>(4, 5)?);
+	Ok(())
+}
+
+#[test]
+fn apply_colormap() -> Result<()> {
+	let data = (0..256).map(|v| v as f32 / 10.).collect::<Vec<_>>();
+	let gradient = Mat::new_rows_cols_with_data(16, 16, &data)?.try_clone()?;
+	let colored = gradient.apply_colormap(ColormapTypes::COLORMAP_JET)?;
+	assert_eq!(Vec3b::opencv_type(), colored.typ());
+	assert_eq!(3, colored.channels());
+	assert_eq!(Size::new(16, 16), colored.size()?);
+	assert_ne!(colored.at_2d::<Vec3b>(0, 0)?, colored.at_2d::<Vec3b>(15, 15)?);
+
+	let gray = Mat::new_rows_cols_with_default(4, 4, u8::opencv_type(), Scalar::all(0.))?;
+	let colored = gray.apply_colormap(ColormapTypes::COLORMAP_VIRIDIS)?;
+	assert_eq!(Vec3b::opencv_type(), colored.typ());
+	assert_eq!(Size::new(4, 4), colored.size()?);
+	Ok(())
+}
@@ -3,7 +3,7 @@ use std::mem;
 
 use matches::assert_matches;
 
-use opencv::core::{MatConstIterator, MatIter, Point, Point2d, Rect, Scalar, Size, Vec2b, Vec2s, Vec3d, Vec3f, Vec4w};
+use opencv::core::{MatConstIterator, MatIter, Point, Point2d, Rect, Scalar, Size, Vec2b, Vec2s, Vec3b, Vec3d, Vec3f, Vec4w};
 use opencv::prelude::*;
 use opencv::types::{VectorOfMat, VectorOfi32};
 use opencv::{core, imgproc, Error, Result};
@@ -875,6 +875,89 @@ fn mat_convert() -> Result<()> {
 	Ok(())
 }
 
+#[test]
+fn mat_convert_clamped() -> Result<()> {
+	let mat = Mat::from_slice(&[-100f32, 0.5, 100., 1000., 1e10])?;
+	let mat_u8 = mat.convert_clamped::<u8>(1., 0.)?;
+	assert_eq!(u8::opencv_type(), mat_u8.typ());
+	assert_eq!(&[0, 0, 100, 255, 255], mat_u8.data_typed::<u8>()?);
+
+	let mat_u8 = mat.convert_clamped::<u8>(2., 10.)?;
+	assert_eq!(&[0, 11, 210, 255, 255], mat_u8.data_typed::<u8>()?);
+
+	let mat_i16 = mat.convert_clamped::<i16>(100., 0.)?;
+	assert_eq!(&[-10000, 50, 10000, 32767, 32767], mat_i16.data_typed::<i16>()?);
+
+	assert_matches!(
+		mat.convert_clamped::<Vec2b>(1., 0.),
+		Err(Error {
+			code: core::StsUnmatchedFormats,
+			..
+		})
+	);
+	Ok(())
+}
+
+#[test]
+fn mat_map() -> Result<()> {
+	let mat = Mat::from_slice_2d(&[[1f32, -2., 3.], [4., 0.5, -6.]])?;
+	let squared = mat.map(|x: f32| x * x)?;
+	assert_eq!(f32::opencv_type(), squared.typ());
+	assert_eq!(mat.size()?, squared.size()?);
+	assert_eq!(vec![vec![1., 4., 9.], vec![16., 0.25, 36.]], squared.to_vec_2d::<f32>()?);
+
+	let roi = Mat::roi(&mat, Rect::new(1, 0, 2, 2))?;
+	let positive = roi.map(|x: f32| u8::from(x > 0.))?;
+	assert_eq!(u8::opencv_type(), positive.typ());
+	assert_eq!(vec![vec![0, 1], vec![1, 0]], positive.to_vec_2d::<u8>()?);
+
+	assert_matches!(
+		mat.map(|x: f64| x),
+		Err(Error {
+			code: core::StsUnmatchedFormats,
+			..
+		})
+	);
+	Ok(())
+}
+
+#[test]
+fn mat_for_each_pixel_mut() -> Result<()> {
+	let mut mat = Mat::new_rows_cols_with_default(2, 3, Vec3b::opencv_type(), Scalar::new(10., 20., 30., 0.))?;
+	mat.for_each_pixel_mut(|pt, pixel: &mut [u8; 3]| {
+		pixel[0] += pt.x as u8;
+		pixel[1] += pt.y as u8;
+		pixel.swap(0, 2);
+	})?;
+	assert_eq!(Vec3b::from([30, 20, 10]), *mat.at_2d::<Vec3b>(0, 0)?);
+	assert_eq!(Vec3b::from([30, 20, 12]), *mat.at_2d::<Vec3b>(0, 2)?);
+	assert_eq!(Vec3b::from([30, 21, 11]), *mat.at_2d::<Vec3b>(1, 1)?);
+
+	{
+		let mut roi = Mat::roi_mut(&mut mat, Rect::new(1, 1, 2, 1))?;
+		roi.for_each_pixel_mut(|_, pixel: &mut [u8; 3]| *pixel = [0; 3])?;
+	}
+	assert_eq!(Vec3b::from([30, 21, 10]), *mat.at_2d::<Vec3b>(1, 0)?);
+	assert_eq!(Vec3b::all(0), *mat.at_2d::<Vec3b>(1, 1)?);
+	assert_eq!(Vec3b::all(0), *mat.at_2d::<Vec3b>(1, 2)?);
+
+	assert_matches!(
+		mat.for_each_pixel_mut(|_, _: &mut [u8; 4]| {}),
+		Err(Error {
+			code: core::StsUnmatchedFormats,
+			..
+		})
+	);
+	assert_matches!(
+		mat.for_each_pixel_mut(|_, _: &mut [u16; 3]| {}),
+		Err(Error {
+			code: core::StsUnmatchedFormats,
+			..
+		})
+	);
+	Ok(())
+}
+
 #[test]
 fn mat_mul() -> Result<()> {
 	{
@@ -1169,3 +1252,68 @@ fn mat_reshape() -> Result<()> {
 
 	Ok(())
 }
+
+#[test]
+fn mat_dft() -> Result<()> {
+	// 4 periods of a cosine over 64 samples
+	let signal = (0..64)
+		.map(|i| (2. * std::f32::consts::PI * 4. * i as f32 / 64.).cos())
+		.collect::<Vec<_>>();
+	let src = Mat::from_slice(&signal)?.try_clone()?;
+
+	let spectrum = src.dft_forward()?;
+	assert_eq!(2, spectrum.channels());
+	assert_eq!(src.size()?, spectrum.size()?);
+
+	let magnitude = src.magnitude_spectrum()?;
+	let magnitude = magnitude.data_typed::<f32>()?;
+	assert_eq!(64, magnitude.len());
+	assert!((magnitude[4] - 32.).abs() < 1e-3);
+	assert!((magnitude[60] - 32.).abs() < 1e-3);
+	assert!(magnitude
+		.iter()
+		.enumerate()
+		.filter(|&(i, _)| i != 4 && i != 60)
+		.all(|(_, &v)| v < 1e-3));
+
+	let restored = spectrum.dft_inverse()?;
+	assert_eq!(1, restored.channels());
+	assert!(restored
+		.data_typed::<f32>()?
+		.iter()
+		.zip(&signal)
+		.all(|(a, b)| (a - b).abs() < 1e-4));
+	Ok(())
+}
+
+#[test]
+fn mat_extract_channel_owned() -> Result<()> {
+	let mat = Mat::new_rows_cols_with_default(2, 3, Vec3b::opencv_type(), Scalar::new(10., 20., 30., 0.))?;
+	for (index, expected) in [10, 20, 30].into_iter().enumerate() {
+		let channel = mat.extract_channel_owned(index as i32)?;
+		assert_eq!(1, channel.channels());
+		assert_eq!(mat.size()?, channel.size()?);
+		assert!(channel.data_typed::<u8>()?.iter().all(|&v| v == expected));
+	}
+	assert_matches!(
+		mat.extract_channel_owned(3),
+		Err(Error {
+			code: core::StsOutOfRange,
+			..
+		})
+	);
+	assert!(mat.extract_channel_owned(-1).is_err());
+	Ok(())
+}
+
+#[test]
+fn mat_in_range() -> Result<()> {
+	let mut mat = Mat::new_rows_cols_with_default(2, 2, Vec3b::opencv_type(), Scalar::new(10., 100., 100., 0.))?;
+	*mat.at_2d_mut::<Vec3b>(0, 1)? = Vec3b::from([50, 100, 100]);
+	*mat.at_2d_mut::<Vec3b>(1, 0)? = Vec3b::from([20, 255, 255]);
+	let mask = mat.in_range(Scalar::new(0., 50., 50., 0.), Scalar::new(20., 255., 255., 0.))?;
+	assert_eq!(u8::opencv_type(), mask.typ());
+	assert_eq!(mat.size()?, mask.size()?);
+	assert_eq!(&[255, 0, 255, 255], mask.data_typed::<u8>()?);
+	Ok(())
+}
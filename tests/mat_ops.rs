@@ -101,3 +101,17 @@ fn mat_ops() -> Result<()> {
 
 	Ok(())
 }
+
+#[test]
+fn mat_bitwise_ops() -> Result<()> {
+	let lhs = Mat::new_rows_cols_with_default(3, 3, u8::opencv_type(), 0b1100.into())?;
+	let rhs = Mat::new_rows_cols_with_default(3, 3, u8::opencv_type(), 0b1010.into())?;
+
+	let res = (&lhs & &rhs)?.to_mat()?;
+	assert_eq!([0b1000; 9], res.data_typed::<u8>()?);
+	let res = (&lhs | &rhs)?.to_mat()?;
+	assert_eq!([0b1110; 9], res.data_typed::<u8>()?);
+	let res = (&lhs ^ &rhs)?.to_mat()?;
+	assert_eq!([0b0110; 9], res.data_typed::<u8>()?);
+	Ok(())
+}
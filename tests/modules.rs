@@ -0,0 +1,28 @@
+/// OpenCV modules are only generated and compiled when their cargo feature is enabled
+#[test]
+fn module_requires_feature() {
+	assert!(!cfg!(ocvrs_has_module_imgproc) || cfg!(feature = "imgproc"));
+	assert!(!cfg!(ocvrs_has_module_dnn) || cfg!(feature = "dnn"));
+	assert!(!cfg!(ocvrs_has_module_videoio) || cfg!(feature = "videoio"));
+}
+
+/// The module enabled by its feature is exported from the crate root and can be called
+#[test]
+fn module_enabled() -> opencv::Result<()> {
+	#![cfg(ocvrs_has_module_imgproc)]
+	use opencv::core::{Mat, Point, Scalar, Size};
+	use opencv::imgproc;
+	use opencv::prelude::*;
+	opencv::opencv_branch_4! {
+		use opencv::imgproc::{FILLED, LINE_8};
+	}
+	opencv::not_opencv_branch_4! {
+		use opencv::core::{FILLED, LINE_8};
+	}
+
+	assert!(cfg!(feature = "imgproc"));
+	let mut mat = Mat::new_size_with_default(Size::new(10, 10), u8::opencv_type(), Scalar::all(0.))?;
+	imgproc::circle(&mut mat, Point::new(5, 5), 2, Scalar::all(255.), FILLED, LINE_8, 0)?;
+	assert_eq!(255, *mat.at_2d::<u8>(5, 5)?);
+	Ok(())
+}
@@ -2,16 +2,20 @@
 
 use std::path::Path;
 
+use opencv::core::Scalar;
 use opencv::{core, imgcodecs, objdetect, prelude::*, types::VectorOfPoint, Result};
 
+/// Workaround for the missing (and not runtime detectable) QUIRC support in repository OpenCV in Ubuntu 20.04
+fn objdetect_missing_quirc() -> Result<bool> {
+	let build_info = core::get_build_information()?;
+	Ok(cfg!(target_os = "linux") && (build_info.contains("/opencv-4.2.0+dfsg/") || build_info.contains("/opencv-4.5.4+dfsg/")))
+}
+
 #[test]
 fn qr_code() -> Result<()> {
 	let qr_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/qr.png");
 
-	// workaround for the missing (and not runtime detectable) QUIRC support in repository OpenCV in Ubuntu 20.04
-	let build_info = core::get_build_information()?;
-	let objdetect_missing_quirc =
-		cfg!(target_os = "linux") && (build_info.contains("/opencv-4.2.0+dfsg/") || build_info.contains("/opencv-4.5.4+dfsg/"));
+	let objdetect_missing_quirc = objdetect_missing_quirc()?;
 
 	{
 		#[allow(unused_mut)]
@@ -91,3 +95,42 @@ fn output_byte_string() -> Result<()> {
 
 	Ok(())
 }
+
+#[test]
+fn detect_qr_codes_blank() -> Result<()> {
+	let blank = Mat::new_rows_cols_with_default(200, 200, u8::opencv_type(), Scalar::all(255.))?;
+	assert!(objdetect::detect_qr_codes(&blank)?.is_empty());
+	Ok(())
+}
+
+#[cfg(all(ocvrs_opencv_branch_4, ocvrs_has_module_imgproc))]
+#[test]
+fn detect_qr_codes() -> Result<()> {
+	use opencv::core::{Rect2f, Size, BORDER_CONSTANT};
+	use opencv::imgproc::{self, INTER_NEAREST};
+	use opencv::objdetect::QRCodeEncoder;
+
+	let mut encoder = QRCodeEncoder::create_def()?;
+	let mut code = Mat::default();
+	encoder.encode("opencv-rust", &mut code)?;
+	let mut scaled = Mat::default();
+	imgproc::resize(&code, &mut scaled, Size::default(), 8., 8., INTER_NEAREST)?;
+	let mut img = Mat::default();
+	core::copy_make_border(&scaled, &mut img, 40, 40, 40, 40, BORDER_CONSTANT, Scalar::all(255.))?;
+
+	let codes = objdetect::detect_qr_codes(&img)?;
+	assert_eq!(1, codes.len());
+	let (text, corners) = &codes[0];
+	if objdetect_missing_quirc()? {
+		assert_eq!("", text);
+	} else {
+		assert_eq!("opencv-rust", text);
+	}
+	assert_eq!(4, corners.len());
+	let code_area = Rect2f::new(30., 30., img.cols() as f32 - 60., img.rows() as f32 - 60.);
+	assert!(
+		corners.iter().all(|corner| code_area.contains(corner)),
+		"Unexpected corners: {corners:?}"
+	);
+	Ok(())
+}
@@ -0,0 +1,23 @@
+#![cfg(ocvrs_has_module_photo)]
+
+use opencv::core::{Mat, Point, Scalar, Size, Vec3b};
+use opencv::photo::{seamless_clone_mat, SeamlessCloneMode};
+use opencv::prelude::*;
+use opencv::Result;
+
+#[test]
+fn seamless_clone() -> Result<()> {
+	let src = Mat::new_rows_cols_with_default(20, 20, Vec3b::opencv_type(), Scalar::new(0., 0., 255., 0.))?;
+	let dst = Mat::new_rows_cols_with_default(100, 120, Vec3b::opencv_type(), Scalar::new(255., 0., 0., 0.))?;
+	let mask = Mat::new_rows_cols_with_default(20, 20, u8::opencv_type(), Scalar::all(255.))?;
+	for mode in [
+		SeamlessCloneMode::Normal,
+		SeamlessCloneMode::Mixed,
+		SeamlessCloneMode::MonochromeTransfer,
+	] {
+		let blend = seamless_clone_mat(&src, &dst, &mask, Point::new(60, 50), mode)?;
+		assert_eq!(Size::new(120, 100), blend.size()?);
+		assert_eq!(dst.typ(), blend.typ());
+	}
+	Ok(())
+}
@@ -1,4 +1,4 @@
-use opencv::core::{Point, Point2d, Point2f, Point2i, Point2l, Size2d, Size2l, Vec2d, Vec2f};
+use opencv::core::{centroid, Point, Point2d, Point2f, Point2i, Point2l, Size, Size2d, Size2l, Vec2d, Vec2f};
 
 #[test]
 fn point_add() {
@@ -34,6 +34,18 @@ fn point_sub() {
 	}
 }
 
+#[test]
+fn point_componentwise() {
+	let a = Point2d::new(1.5, -2.);
+	let b = Point2d::new(-0.5, 10.);
+	assert_eq!(a + b, Point2d::new(1., 8.));
+	assert_eq!(a - b, Point2d::new(2., -12.));
+	let mut out = a;
+	out += b;
+	out -= a;
+	assert_eq!(out, b);
+}
+
 #[test]
 fn point_mul() {
 	let src = Point2f::new(50., 50.);
@@ -106,3 +118,212 @@ fn point_from() {
 	assert_eq!(Point2f::new(1., 2.), Vec2f::from([1., 2.]).into());
 	assert_eq!(Point2d::new(1., 2.), Size2d::new(1., 2.).into());
 }
+
+#[test]
+fn point_tuple_array_conv() {
+	let pt = Point::new(3, -4);
+	assert_eq!(pt, Point::from((3, -4)));
+	assert_eq!(pt, Point::from([3, -4]));
+	assert_eq!((3, -4), <(i32, i32)>::from(pt));
+	assert_eq!([3, -4], <[i32; 2]>::from(pt));
+	let tuple: (f64, f64) = Point2d::new(1.5, 2.5).into();
+	assert_eq!(Point2d::new(1.5, 2.5), tuple.into());
+	let arr: [f64; 2] = Point2d::new(1.5, 2.5).into();
+	assert_eq!(Point2d::new(1.5, 2.5), arr.into());
+}
+
+#[test]
+fn point_wrap() {
+	let bounds = Size::new(10, 20);
+	assert_eq!(Point::new(3, 4), Point::new(3, 4).wrap(bounds));
+	assert_eq!(Point::new(0, 0), Point::new(10, 20).wrap(bounds));
+	assert_eq!(Point::new(2, 5), Point::new(12, 25).wrap(bounds));
+	assert_eq!(Point::new(9, 19), Point::new(-1, -1).wrap(bounds));
+	assert_eq!(Point::new(7, 15), Point::new(-13, -45).wrap(bounds));
+
+	let bounds = Size2d::new(360., 180.);
+	assert_eq!(Point2d::new(10., 170.), Point2d::new(370., -10.).wrap(bounds));
+	assert_eq!(Point2d::new(0.5, 90.), Point2d::new(-359.5, 90.).wrap(bounds));
+}
+
+#[test]
+fn point_sum_centroid() {
+	let points = [Point::new(0, 0), Point::new(4, 0), Point::new(4, 2), Point::new(0, 2)];
+	assert_eq!(Point::new(8, 4), points.iter().copied().sum());
+	assert_eq!(Point2f::new(0., 0.), Vec::<Point2f>::new().into_iter().sum());
+
+	assert_eq!(Some(Point2d::new(2., 1.)), centroid(&points));
+	assert_eq!(
+		Some(Point2d::new(1. / 3., 1. / 3.)),
+		centroid(&[Point::new(0, 0), Point::new(1, 1), Point::new(0, 0)])
+	);
+	assert_eq!(
+		Some(Point2d::new(-1.5, 2.5)),
+		centroid(&[Point2f::new(-1., 2.), Point2f::new(-2., 3.)])
+	);
+	assert_eq!(Some(Point2d::new(5., 7.)), centroid(&[Point2l::new(5, 7)]));
+	assert_eq!(None, centroid::<i32>(&[]));
+}
+
+#[test]
+fn point_zero() {
+	assert_eq!(Point::new(0, 0), Point::zero());
+	assert_eq!(Point::default(), Point::origin());
+	assert_eq!(Point2d::new(0., 0.), Point2d::zero());
+	assert_eq!(Point2d::zero(), Point2d::origin());
+	assert_eq!(Point2l::new(0, 0), Point2l::origin());
+}
+
+#[test]
+fn point_format_precision() {
+	let pt = Point2d::new(1.23456, -4.56);
+	assert_eq!("(1, -5)", pt.format_precision(0));
+	assert_eq!("(1.23, -4.56)", pt.format_precision(2));
+	assert_eq!("(1.2346, -4.5600)", pt.format_precision(4));
+	assert_eq!("(3, 4)", Point::new(3, 4).format_precision(2));
+}
+
+#[test]
+fn point_negate_perpendicular() {
+	assert_eq!(Point::new(0, 1), Point::new(1, 0).perpendicular());
+	assert_eq!(Point::new(-1, 0), Point::new(0, 1).perpendicular());
+	let pt = Point2d::new(3., -4.5);
+	assert_eq!(Point2d::new(4.5, 3.), pt.perpendicular());
+	assert_eq!(0., pt.dot(pt.perpendicular()));
+	assert_eq!(Point2d::new(-3., 4.5), pt.negate());
+	// two 90 degree rotations make the 180 degree one
+	assert_eq!(pt.negate(), pt.perpendicular().perpendicular());
+	assert_eq!(Point::new(-2, 7), Point::new(2, -7).negate());
+}
+
+#[test]
+fn point_abs() {
+	assert_eq!(Point::new(3, 4), Point::new(-3, 4).abs());
+	assert_eq!(Point::new(3, 4), Point::new(3, -4).abs());
+	assert_eq!(Point2d::new(1.5, 0.), Point2d::new(-1.5, -0.).abs());
+	assert_eq!(Point2f::new(2., 3.), Point2f::new(2., 3.).abs());
+}
+
+#[test]
+fn point_round_floor_ceil() {
+	let pt = Point2d::new(1.5, -2.3);
+	assert_eq!(Point::new(2, -2), pt.round());
+	assert_eq!(Point::new(1, -3), pt.floor());
+	assert_eq!(Point::new(2, -2), pt.ceil());
+	assert_eq!(Some(Point::new(1, -2)), pt.to::<i32>());
+
+	let pt = Point2d::new(-0.5, 2.7);
+	assert_eq!(Point::new(-1, 3), pt.round());
+	assert_eq!(Point::new(-1, 2), pt.floor());
+	assert_eq!(Point::new(0, 3), pt.ceil());
+
+	assert_eq!(Point::new(i32::MAX, i32::MIN), Point2d::new(1e12, -1e12).round());
+}
+
+#[test]
+fn point_serde() {
+	#![cfg(feature = "serde")]
+	let pt = Point::new(1, -2);
+	let json = serde_json::to_string(&pt).unwrap();
+	assert_eq!(r#"{"x":1,"y":-2}"#, json);
+	assert_eq!(pt, serde_json::from_str::<Point>(&json).unwrap());
+
+	let pt = Point2d::new(1.5, -2.);
+	let json = serde_json::to_string(&pt).unwrap();
+	assert_eq!(r#"{"x":1.5,"y":-2.0}"#, json);
+	assert_eq!(pt, serde_json::from_str::<Point2d>(&json).unwrap());
+}
+
+#[test]
+fn point_dot_cross_norm() {
+	assert_eq!(5., Point::new(3, 4).norm());
+	assert_eq!(5., Point2d::new(-3., -4.).norm());
+
+	// counterclockwise from x to y is positive, clockwise is negative
+	let x = Point::new(1, 0);
+	let y = Point::new(0, 1);
+	assert_eq!(1., x.cross(y));
+	assert_eq!(-1., y.cross(x));
+	assert_eq!(-10., Point2d::new(1., 2.).cross(Point2d::new(3., -4.)));
+
+	assert_eq!(0, x.dot(y));
+	assert_eq!(-5., Point2d::new(1., 2.).dot(Point2d::new(3., -4.)));
+}
+
+#[test]
+fn point_distance() {
+	let a = Point::new(1, 2);
+	let b = Point::new(4, -2);
+	assert_eq!(5., a.distance(b));
+	assert_eq!(25., a.distance_sq(b));
+	assert_eq!(7., a.manhattan_distance(b));
+	assert_eq!(a.distance(b), b.distance(a));
+	assert_eq!(0., a.distance(a));
+
+	let a = Point2d::new(0.5, -1.25);
+	let b = Point2d::new(-2., 3.);
+	assert!((a.distance(b).powi(2) - a.distance_sq(b)).abs() < 1e-12);
+	assert_eq!(6.75, a.manhattan_distance(b));
+
+	// no overflow for the far apart integer points
+	let a = Point::new(i32::MIN, i32::MIN);
+	let b = Point::new(i32::MAX, i32::MAX);
+	let d = f64::from(i32::MAX) - f64::from(i32::MIN);
+	assert_eq!(2. * d * d, a.distance_sq(b));
+	assert_eq!(2. * d, a.manhattan_distance(b));
+}
+
+#[test]
+fn point_lerp() {
+	let from = Point::new(0, 10);
+	let to = Point::new(5, -10);
+	assert_eq!(Point2d::new(0., 10.), from.lerp(to, 0.));
+	assert_eq!(Point2d::new(2.5, 0.), from.lerp(to, 0.5));
+	assert_eq!(Point2d::new(5., -10.), from.lerp(to, 1.));
+	assert_eq!(Point2d::new(10., -30.), from.lerp(to, 2.));
+	assert_eq!(Point2d::new(-5., 30.), from.lerp(to, -1.));
+
+	let from = Point2f::new(1., 1.);
+	let to = Point2f::new(2., 3.);
+	assert_eq!(Point2d::new(1.25, 1.5), from.lerp(to, 0.25));
+}
+
+#[test]
+fn point_rotate() {
+	let assert_near = |expected: Point2d, actual: Point2d| {
+		assert!((expected - actual).norm() < 1e-10, "{actual:?} is not close to {expected:?}");
+	};
+	let origin = Point2d::new(0., 0.);
+	let pt = Point2d::new(1., 0.);
+	assert_near(Point2d::new(0., 1.), pt.rotate(origin, std::f64::consts::FRAC_PI_2));
+	assert_near(Point2d::new(-1., 0.), pt.rotate(origin, std::f64::consts::PI));
+	assert_near(Point2d::new(0., 1.), pt.rotate_deg(origin, 90.));
+	assert_near(Point2d::new(-1., 0.), pt.rotate_deg(origin, 180.));
+	assert_near(Point2d::new(0., -1.), pt.rotate_deg(origin, -90.));
+	assert_near(pt, pt.rotate_deg(origin, 360.));
+
+	let center = Point2d::new(2., 3.);
+	assert_near(Point2d::new(2., 2.), Point2d::new(3., 3.).rotate_deg(center, -90.));
+	assert_near(center, center.rotate_deg(center, 45.));
+}
+
+#[test]
+fn point_dot_i64() {
+	let (a, b) = (Point::new(1, 2), Point::new(3, 4));
+	assert_eq!(11, a.dot_i64(b));
+	assert_eq!(i64::from(a.dot(b)), a.dot_i64(b));
+
+	let (min, max) = (i64::from(i32::MIN), i64::from(i32::MAX));
+	let max_max = Point::new(i32::MAX, i32::MAX);
+	let min_max = Point::new(i32::MIN, i32::MAX);
+	assert_eq!(2 * max * max, max_max.dot_i64(max_max));
+	assert_eq!(min * min + max * max, min_max.dot_i64(min_max));
+	assert_eq!(min * max + max * max, min_max.dot_i64(max_max));
+}
+
+#[test]
+fn point_display() {
+	assert_eq!("[1, -2]", Point::new(1, -2).to_string());
+	assert_eq!("[1.5, -2]", Point2d::new(1.5, -2.).to_string());
+	assert_eq!("[0.25, 3]", Point2f::new(0.25, 3.).to_string());
+}
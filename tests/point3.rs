@@ -1,4 +1,4 @@
-use opencv::core::{Point2d, Point2f, Point3d, Point3f, Point3i, Vec3d, Vec3i};
+use opencv::core::{Point2d, Point2f, Point2i, Point3d, Point3f, Point3i, Vec3d, Vec3i};
 
 #[test]
 fn point3_add() {
@@ -107,3 +107,18 @@ fn point3_from() {
 	assert_eq!(Point3i::new(1, 2, 3), Vec3i::from([1, 2, 3]).into());
 	assert_eq!(Point3f::new(1., 2., 0.), Point2f::new(1., 2.).into());
 }
+
+#[test]
+fn point3_homogeneous() {
+	let pt = Point2d::new(3., -4.);
+	let homogeneous = pt.to_homogeneous();
+	assert_eq!(Point3d::new(3., -4., 1.), homogeneous);
+	assert_eq!(Some(pt), homogeneous.from_homogeneous());
+	assert_eq!(Some(Point2d::new(1.5, -2.)), Point3d::new(3., -4., 2.).from_homogeneous());
+	assert_eq!(
+		Some(Point2i::new(2, 3)),
+		Point2i::new(2, 3).to_homogeneous().from_homogeneous()
+	);
+	assert_eq!(None, Point3d::new(3., -4., 0.).from_homogeneous());
+	assert_eq!(None, Point3i::new(3, -4, 0).from_homogeneous());
+}
@@ -1,4 +1,9 @@
-use opencv::core::{self, Point2d, Point2f, Point2i, Rect, Rect2d, Rect2f, Rect2i, Size2d, Size2f, Size2i};
+use std::collections::HashSet;
+
+use opencv::core::{
+	self, AlignMode, Point2d, Point2f, Point2i, Point_, Rect, Rect2d, Rect2f, Rect2i, RectScalar, Rect_, Size2d, Size2f, Size2i,
+	Size_,
+};
 
 #[test]
 fn rect_add() {
@@ -142,6 +147,35 @@ fn rect_union() {
 	}
 }
 
+#[test]
+fn rect_intersect_union_touching_contained() {
+	// touching edges share no area
+	let a = Rect::new(0, 0, 10, 10);
+	let b = Rect::new(10, 0, 10, 10);
+	assert!((a & b).empty());
+	assert_eq!(Rect::default(), a & b);
+	assert_eq!(Rect::new(0, 0, 20, 10), a | b);
+
+	let a = Rect2d::new(0., 0., 10., 10.);
+	let b = Rect2d::new(0., 10., 10., 10.);
+	assert!((a & b).empty());
+	assert_eq!(Rect2d::new(0., 0., 10., 20.), a | b);
+
+	// fully contained
+	let outer = Rect2f::new(0., 0., 100., 100.);
+	let inner = Rect2f::new(25., 25., 50., 50.);
+	assert_eq!(inner, outer & inner);
+	assert_eq!(inner, inner & outer);
+	assert_eq!(outer, outer | inner);
+	assert_eq!(outer, inner | outer);
+
+	let mut r = inner;
+	r |= outer;
+	assert_eq!(outer, r);
+	r &= inner;
+	assert_eq!(inner, r);
+}
+
 #[test]
 fn rect_constructor() {
 	let r = Rect2i::from_point_size(Point2i::new(1, 2), Size2i::new(3, 4));
@@ -149,6 +183,11 @@ fn rect_constructor() {
 	assert_eq!(2, r.y);
 	assert_eq!(3, r.width);
 	assert_eq!(4, r.height);
+	assert_eq!(r, Rect2i::at(Point2i::new(1, 2), Size2i::new(3, 4)));
+	assert_eq!(
+		Rect2f::new(0.5, 1.5, 2., 3.),
+		Rect2f::at(Point2f::new(0.5, 1.5), Size2f::new(2., 3.))
+	);
 
 	let r = Rect2d::from_points(Point2d::new(10., 20.), Point2d::new(20., 10.));
 	assert_eq!(10., r.x);
@@ -174,6 +213,120 @@ fn rect_methods() {
 	assert_eq!(rect.empty(), rect.size().empty());
 }
 
+#[test]
+fn rect_contains_rect() {
+	let rect = Rect::new(10, 20, 100, 200);
+	assert!(rect.contains_rect(&Rect::new(20, 30, 50, 50)));
+	assert!(rect.contains_rect(&rect));
+	assert!(!rect.contains_rect(&Rect::new(5, 30, 50, 50)));
+	assert!(!rect.contains_rect(&Rect::new(80, 30, 50, 50)));
+	assert!(!rect.contains_rect(&Rect::new(20, 200, 50, 50)));
+	assert!(!rect.contains_rect(&Rect::new(0, 0, 200, 300)));
+	// empty rect is contained vacuously
+	assert!(rect.contains_rect(&Rect::new(500, 500, 0, 10)));
+	assert!(Rect::default().contains_rect(&Rect::default()));
+
+	let rect = Rect2d::new(0., 0., 1., 1.);
+	assert!(rect.contains_rect(&Rect2d::new(0.25, 0.25, 0.75, 0.75)));
+	assert!(!rect.contains_rect(&Rect2d::new(0.25, 0.25, 0.8, 0.75)));
+}
+
+#[test]
+fn rect_contains_rect_edges() {
+	let rect = Rect::new(0, 0, 10, 10);
+	// sharing the right and bottom edges
+	assert!(rect.contains_rect(&Rect::new(5, 5, 5, 5)));
+	assert!(rect.contains_rect(&Rect::new(0, 0, 10, 5)));
+	// touching from the outside
+	assert!(!rect.contains_rect(&Rect::new(10, 0, 5, 5)));
+	assert!(!rect.contains_rect(&Rect::new(0, -5, 5, 5)));
+	// partial overlap
+	assert!(!rect.contains_rect(&Rect::new(5, 5, 6, 5)));
+	assert!(!rect.contains_rect(&Rect::new(-1, 2, 5, 5)));
+	// an empty rect with the origin inside
+	assert!(rect.contains_rect(&Rect::new(3, 3, 0, 0)));
+
+	let rect = Rect2f::new(0.5, 0.5, 1., 1.);
+	assert!(rect.contains_rect(&rect));
+	assert!(rect.contains_rect(&Rect2f::new(1., 1., 0.5, 0.5)));
+	assert!(!rect.contains_rect(&Rect2f::new(1., 1., 0.5, 0.75)));
+}
+
+#[test]
+fn rect_bounding() {
+	let points = [
+		Point2i::new(5, 7),
+		Point2i::new(-3, 10),
+		Point2i::new(12, -1),
+		Point2i::new(0, 0),
+		Point2i::new(8, 15),
+	];
+	assert_eq!(Some(Rect::new(-3, -1, 15, 16)), Rect::bounding(points));
+	assert_eq!(Some(Rect2f::new(1.5, 2., 0., 0.)), Rect2f::bounding([Point2f::new(1.5, 2.)]));
+	assert_eq!(
+		Some(Rect2d::new(-1., -2., 3., 4.)),
+		Rect2d::bounding(vec![Point2d::new(2., -2.), Point2d::new(-1., 2.)])
+	);
+	assert_eq!(None, Rect::bounding([]));
+	assert_eq!(None, Rect2f::bounding(Vec::new()));
+}
+
+#[test]
+fn rect_inset_percent() {
+	assert_eq!(Rect::new(10, 10, 80, 80), Rect::new(0, 0, 100, 100).inset_percent(10.));
+	assert_eq!(Rect::new(15, 27, 70, 46), Rect::new(5, 20, 90, 60).inset_percent(11.));
+	assert_eq!(Rect::new(-10, -10, 120, 120), Rect::new(0, 0, 100, 100).inset_percent(-10.));
+	assert_eq!(Rect::new(0, 0, 100, 100), Rect::new(0, 0, 100, 100).inset_percent(0.));
+	assert_eq!(
+		Rect2f::new(10., 10., 80., 80.),
+		Rect2f::new(0., 0., 100., 100.).inset_percent(10.)
+	);
+	assert_eq!(
+		Rect2d::new(12., 21., 76., 38.),
+		Rect2d::new(10., 20., 80., 40.).inset_percent(2.5)
+	);
+}
+
+#[test]
+fn rect_scalar() {
+	fn check<T: RectScalar + std::fmt::Debug>(n: impl Fn(i32) -> T) {
+		let rect = Rect_::new(n(10), n(20), n(30), n(40));
+		assert_eq!(
+			rect,
+			Rect_::from_point_size(Point_::new(n(10), n(20)), Size_::new(n(30), n(40)))
+		);
+		assert_eq!(rect, Rect_::from_points(Point_::new(n(40), n(60)), Point_::new(n(10), n(20))));
+		assert_eq!(rect, Rect_::from((Point_::new(n(10), n(20)), Point_::new(n(40), n(60)))));
+		assert_eq!(
+			Some(rect),
+			Rect_::bounding([Point_::new(n(10), n(60)), Point_::new(n(40), n(20))])
+		);
+		assert_eq!(Point_::new(n(10), n(20)), rect.tl());
+		assert_eq!(Point_::new(n(40), n(60)), rect.br());
+		assert_eq!(Size_::new(n(30), n(40)), rect.size());
+		assert_eq!(n(1200), rect.area());
+		assert!(!rect.empty());
+		assert!(Rect_::new(n(10), n(20), n(0), n(40)).empty());
+		assert!(rect.contains(Point_::new(n(10), n(20))));
+		assert!(!rect.contains(rect.br()));
+		assert!(Point_::new(n(15), n(25)).inside(rect));
+		assert!(rect.contains_rect(&Rect_::new(n(15), n(25), n(5), n(5))));
+		assert_eq!(Some(Rect::new(10, 20, 30, 40)), rect.to::<i32>());
+		assert_eq!(
+			Rect_::new(n(10), n(20), n(40), n(50)),
+			rect | Rect_::new(n(20), n(30), n(30), n(40))
+		);
+		assert_eq!(
+			Rect_::new(n(20), n(30), n(20), n(30)),
+			rect & Rect_::new(n(20), n(30), n(30), n(40))
+		);
+	}
+
+	check(|v| v);
+	check(|v| v as f32);
+	check(f64::from);
+}
+
 #[test]
 fn rect_conv() {
 	let rectf = Rect2d::new(1.2, 2.3, 3.4, 4.5);
@@ -195,3 +348,355 @@ fn rect_from() {
 		(Point2d::new(1., 2.), Point2d::new(4., 6.)).into()
 	);
 }
+
+#[test]
+fn rect_align_to_pixels() {
+	let r = Rect2d::new(1.2, 2.7, 3.5, 4.1);
+	assert_eq!(Rect::new(2, 3, 2, 3), r.align_to_pixels(AlignMode::Inner));
+	assert_eq!(Rect::new(1, 2, 4, 5), r.align_to_pixels(AlignMode::Outer));
+	assert_eq!(Rect::new(1, 3, 4, 4), r.align_to_pixels(AlignMode::Round));
+
+	let r = Rect2d::new(-1.5, -0.2, 2., 1.4);
+	assert_eq!(Rect::new(-1, 0, 1, 1), r.align_to_pixels(AlignMode::Inner));
+	assert_eq!(Rect::new(-2, -1, 3, 3), r.align_to_pixels(AlignMode::Outer));
+	assert_eq!(Rect::new(-2, 0, 3, 1), r.align_to_pixels(AlignMode::Round));
+
+	let aligned = Rect2d::new(1., 2., 3., 4.);
+	for mode in [AlignMode::Inner, AlignMode::Outer, AlignMode::Round] {
+		assert_eq!(Rect::new(1, 2, 3, 4), aligned.align_to_pixels(mode));
+	}
+
+	let thin = Rect2d::new(1.2, 1.2, 0.5, 0.5);
+	assert_eq!(Rect::new(2, 2, 0, 0), thin.align_to_pixels(AlignMode::Inner));
+	assert_eq!(Rect::new(1, 1, 1, 1), thin.align_to_pixels(AlignMode::Outer));
+}
+
+#[test]
+fn rect_lerp() {
+	let from = Rect::new(0, 10, 100, 50);
+	let to = Rect::new(10, 20, 201, 0);
+	assert_eq!(from, from.lerp(&to, 0.));
+	assert_eq!(Rect::new(5, 15, 151, 25), from.lerp(&to, 0.5));
+	assert_eq!(to, from.lerp(&to, 1.));
+	assert_eq!(Rect::new(20, 30, 302, -50), from.lerp(&to, 2.));
+
+	let from = Rect2d::new(0., 0., 1., 1.);
+	let to = Rect2d::new(1., -1., 2., 3.);
+	assert_eq!(from, from.lerp(&to, 0.));
+	assert_eq!(Rect2d::new(0.5, -0.5, 1.5, 2.), from.lerp(&to, 0.5));
+	assert_eq!(to, from.lerp(&to, 1.));
+	assert_eq!(
+		Rect2f::new(0.25, -0.25, 1.25, 1.5),
+		from.to::<f32>().unwrap().lerp(&to.to().unwrap(), 0.25)
+	);
+}
+
+#[test]
+fn rect_center() {
+	assert_eq!(Point2i::new(12, 23), Rect::new(10, 20, 4, 6).center());
+	// odd sizes are truncated
+	assert_eq!(Point2i::new(2, 1), Rect::new(0, 0, 5, 3).center());
+	assert_eq!(Point2i::new(-8, -9), Rect::new(-10, -10, 5, 3).center());
+	assert_eq!(Point2d::new(2.5, 1.75), Rect2d::new(0., 0.5, 5., 2.5).center());
+}
+
+#[test]
+fn rect_map() {
+	assert_eq!(Rect::new(2, -4, 6, 8), Rect::new(1, -2, 3, 4).map(|v| v * 2));
+	assert_eq!(Rect2d::new(1., 2., 3., 4.), Rect2d::new(1.2, 1.5, 3.4, 4.).map(f64::round));
+	assert_eq!(Rect::new(0, 0, 10, 5), Rect::new(-3, 0, 12, 5).map(|v| v.clamp(0, 10)));
+}
+
+#[test]
+fn rect_from_center_size() {
+	let r = Rect::from_center_size(Point2i::new(10, 20), Size2i::new(4, 6));
+	assert_eq!(Rect::new(8, 17, 4, 6), r);
+	assert_eq!(Point2i::new(10, 20), r.center());
+
+	// odd sizes are truncated
+	let r = Rect::from_center_size(Point2i::new(10, 20), Size2i::new(5, 3));
+	assert_eq!(Rect::new(8, 19, 5, 3), r);
+	assert_eq!(Point2i::new(10, 20), r.center());
+
+	let r = Rect2d::from_center_size(Point2d::new(2.5, 1.75), Size2d::new(5., 2.5));
+	assert_eq!(Rect2d::new(0., 0.5, 5., 2.5), r);
+	assert_eq!(Point2d::new(2.5, 1.75), r.center());
+
+	let r = Rect2f::from_center_size(Point2f::new(-1., 3.), Size2f::new(3., 1.));
+	assert_eq!(Rect2f::new(-2.5, 2.5, 3., 1.), r);
+	assert_eq!(Point2f::new(-1., 3.), r.center());
+}
+
+#[test]
+fn rect_inflate() {
+	let r = Rect::new(10, 20, 30, 40);
+	assert_eq!(Rect::new(5, 18, 40, 44), r.inflate(5, 2));
+	assert_eq!(Rect::new(15, 22, 20, 36), r.inflate(-5, -2));
+	assert_eq!(r, r.inflate(0, 0));
+	assert_eq!(r, r.inflate(5, 2).inflate(-5, -2));
+
+	let shrunk = r.inflate(-20, 0);
+	assert_eq!(Rect::new(30, 20, -10, 40), shrunk);
+	assert!(shrunk.empty());
+
+	assert_eq!(
+		Rect2d::new(-0.5, 0.75, 3., 1.5),
+		Rect2d::new(0., 1., 2., 1.).inflate(0.5, 0.25)
+	);
+}
+
+#[test]
+fn rect_iou() {
+	let rect = Rect::new(0, 0, 10, 10);
+	assert_eq!(1., rect.iou(&rect));
+	assert_eq!(0., rect.iou(&Rect::new(20, 20, 10, 10)));
+	assert_eq!(0., rect.iou(&Rect::new(10, 0, 10, 10)));
+	// half of each rect overlaps: 50 / 150
+	assert_eq!(1. / 3., rect.iou(&Rect::new(5, 0, 10, 10)));
+	assert_eq!(0.25, rect.iou(&Rect::new(0, 0, 5, 5)));
+	assert_eq!(0., rect.iou(&Rect::new(2, 2, 0, 5)));
+	assert_eq!(0., Rect::default().iou(&Rect::default()));
+
+	let rect = Rect2d::new(0., 0., 2., 2.);
+	assert_eq!(1. / 7., rect.iou(&Rect2d::new(1., 1., 2., 2.)));
+
+	// areas don't fit into i32
+	let rect = Rect::new(0, 0, 50_000, 50_000);
+	assert_eq!(1., rect.iou(&rect));
+	assert_eq!(1. / 3., rect.iou(&Rect::new(25_000, 0, 50_000, 50_000)));
+	assert_eq!(0.25, rect.iou(&Rect::new(0, 0, 25_000, 25_000)));
+}
+
+#[test]
+fn rect_clip() {
+	let bounds = Rect::new(0, 0, 100, 50);
+	let size = Size2i::new(100, 50);
+	let inside = Rect::new(10, 10, 20, 20);
+	assert_eq!(inside, inside.clip(&bounds));
+	assert_eq!(inside, inside.clip_to_size(size));
+	assert_eq!(bounds, bounds.clip_to_size(size));
+
+	// left, top, right and bottom
+	assert_eq!(Rect::new(0, 10, 15, 20), Rect::new(-5, 10, 20, 20).clip_to_size(size));
+	assert_eq!(Rect::new(10, 0, 20, 10), Rect::new(10, -10, 20, 20).clip_to_size(size));
+	assert_eq!(Rect::new(90, 10, 10, 20), Rect::new(90, 10, 20, 20).clip_to_size(size));
+	assert_eq!(Rect::new(10, 40, 20, 10), Rect::new(10, 40, 20, 20).clip_to_size(size));
+	assert_eq!(bounds, Rect::new(-10, -10, 200, 200).clip(&bounds));
+
+	let outside = Rect::new(150, 10, 20, 20).clip_to_size(size);
+	assert!(outside.empty());
+	assert_eq!(Rect::default(), outside);
+	assert_eq!(Rect::default(), Rect::new(-30, -30, 20, 20).clip(&bounds));
+
+	assert_eq!(
+		Rect2d::new(0., 0.5, 1.5, 2.),
+		Rect2d::new(-1., 0.5, 2.5, 3.).clip(&Rect2d::new(0., 0., 4., 2.5))
+	);
+}
+
+#[test]
+fn rect_scale() {
+	assert_eq!(Rect::new(20, -40, 60, 80), Rect::new(10, -20, 30, 40) * 2);
+	assert_eq!(Rect2d::new(0.5, 1., 1.5, 2.), Rect2d::new(1., 2., 3., 4.) * 0.5);
+	let mut r = Rect2f::new(1., 2., 3., 4.);
+	r *= 3.;
+	assert_eq!(Rect2f::new(3., 6., 9., 12.), r);
+
+	let r = Rect::new(3, 5, 7, 9);
+	assert_eq!(Rect2d::new(1.5, 10., 3.5, 18.), r.scale_f64(0.5, 2.));
+	// casting back truncates
+	assert_eq!(Some(Rect::new(1, 10, 3, 18)), r.scale_f64(0.5, 2.).to::<i32>());
+	assert_eq!(Some(Rect::new(2, 1, 4, 3)), r.scale_f64(2. / 3., 1. / 3.).to::<i32>());
+	assert_eq!(Rect2d::new(3., 5., 7., 9.), r.scale_f64(1., 1.));
+}
+
+#[test]
+fn rect_from_points_nan() {
+	let r = Rect2f::from_points(Point2f::new(f32::NAN, 1.), Point2f::new(3., 4.));
+	assert!(r.x.is_nan());
+	assert!(r.width.is_nan());
+	assert_eq!(1., r.y);
+	assert_eq!(3., r.height);
+
+	let r = Rect2d::from_points(Point2d::new(1., 2.), Point2d::new(3., f64::NAN));
+	assert_eq!(1., r.x);
+	assert_eq!(2., r.width);
+	assert!(r.y.is_nan());
+	assert!(r.height.is_nan());
+
+	assert_eq!(
+		None,
+		Rect2f::try_from_points(Point2f::new(f32::NAN, 1.), Point2f::new(3., 4.))
+	);
+	assert_eq!(
+		None,
+		Rect2d::try_from_points(Point2d::new(1., 2.), Point2d::new(3., f64::NAN))
+	);
+	assert_eq!(
+		Some(Rect2f::new(1., 1., 2., 3.)),
+		Rect2f::try_from_points(Point2f::new(3., 1.), Point2f::new(1., 4.))
+	);
+	assert_eq!(
+		Some(Rect::new(1, 1, 2, 3)),
+		Rect::try_from_points(Point2i::new(3, 4), Point2i::new(1, 1))
+	);
+}
+
+#[test]
+fn rect_display() {
+	assert_eq!("[30 x 40 from (10, 20)]", Rect::new(10, 20, 30, 40).to_string());
+	assert_eq!("[3 x 4.25 from (-1.5, 2)]", Rect2d::new(-1.5, 2., 3., 4.25).to_string());
+}
+
+#[test]
+fn rect_normalize() {
+	assert_eq!(Rect::new(10, 20, 4, 5), Rect::new(10, 20, 4, 5).normalize());
+	assert_eq!(Rect::new(6, 20, 4, 5), Rect::new(10, 20, -4, 5).normalize());
+	assert_eq!(Rect::new(10, 15, 4, 5), Rect::new(10, 20, 4, -5).normalize());
+	assert_eq!(Rect::new(6, 15, 4, 5), Rect::new(10, 20, -4, -5).normalize());
+	assert_eq!(Rect::new(10, 20, 0, 0), Rect::new(10, 20, 0, 0).normalize());
+	assert_eq!(Rect2d::new(-1.5, 0., 2.5, 1.), Rect2d::new(1., 1., -2.5, -1.).normalize());
+}
+
+#[test]
+fn rect_abs_dimensions() {
+	assert_eq!(Rect::new(-10, 20, 4, 5), Rect::new(-10, 20, -4, 5).abs_dimensions());
+	assert_eq!(Rect::new(10, -20, 4, 5), Rect::new(10, -20, 4, -5).abs_dimensions());
+	assert_eq!(Rect::new(1, 2, 3, 4), Rect::new(1, 2, 3, 4).abs_dimensions());
+	assert_eq!(Rect2d::new(1., 1., 2.5, 1.), Rect2d::new(1., 1., -2.5, -1.).abs_dimensions());
+}
+
+#[test]
+fn rect_points() {
+	let rect = Rect::new(10, 20, 2, 3);
+	let points = rect.into_iter();
+	assert_eq!(6, points.len());
+	assert_eq!(
+		vec![
+			Point2i::new(10, 20),
+			Point2i::new(11, 20),
+			Point2i::new(10, 21),
+			Point2i::new(11, 21),
+			Point2i::new(10, 22),
+			Point2i::new(11, 22),
+		],
+		points.collect::<Vec<_>>()
+	);
+
+	let mut count = 0;
+	for pt in &rect {
+		assert!(rect.contains(pt));
+		count += 1;
+	}
+	assert_eq!(rect.area(), count);
+
+	let mut points = Rect::new(-1, -1, 1, 2).into_iter();
+	assert_eq!(Some(Point2i::new(-1, -1)), points.next());
+	assert_eq!(1, points.len());
+	assert_eq!(Some(Point2i::new(-1, 0)), points.next());
+	assert_eq!(None, points.next());
+	assert_eq!(0, points.len());
+
+	assert_eq!(0, Rect::new(5, 5, 0, 3).into_iter().len());
+	assert_eq!(None, Rect::new(5, 5, 3, 0).into_iter().next());
+	assert_eq!(0, Rect::new(5, 5, -3, 3).into_iter().count());
+}
+
+#[test]
+fn rect_checked_area() {
+	assert_eq!(Some(6), Rect::new(1, 2, 2, 3).checked_area());
+	assert_eq!(6, Rect::new(1, 2, 2, 3).area_i64());
+
+	let huge = Rect::new(0, 0, 50000, 50000);
+	assert!(50000i64 * 50000 > i64::from(i32::MAX));
+	assert_eq!(None, huge.checked_area());
+	assert_eq!(2_500_000_000, huge.area_i64());
+	assert_eq!(None, Rect::new(0, 0, i32::MAX, 2).checked_area());
+	assert_eq!(-2 * i64::from(i32::MAX), Rect::new(0, 0, i32::MAX, -2).area_i64());
+}
+
+#[test]
+fn rect_intersection_area() {
+	let a = Rect::new(0, 0, 10, 10);
+	let b = Rect::new(5, 6, 10, 10);
+	assert_eq!(20, a.intersection_area(&b));
+	assert_eq!(20, b.intersection_area(&a));
+	assert_eq!((a & b).area(), a.intersection_area(&b));
+	assert_eq!(20, a.intersection_area_i64(&b));
+	assert_eq!(100, a.intersection_area(&a));
+	assert_eq!(16, a.intersection_area(&Rect::new(2, 3, 4, 4)));
+
+	// disjoint and touching
+	assert_eq!(0, a.intersection_area(&Rect::new(20, 20, 5, 5)));
+	assert_eq!(0, a.intersection_area(&Rect::new(10, 0, 5, 5)));
+	assert_eq!(0, a.intersection_area(&Rect::new(-5, 3, 5, 5)));
+	assert_eq!(0, a.intersection_area(&Rect::new(15, -20, 5, 50)));
+	assert_eq!(0, a.intersection_area_i64(&Rect::new(20, 20, 5, 5)));
+	assert_eq!(0, a.intersection_area(&Rect::default()));
+
+	let a = Rect2d::new(0., 0., 2.5, 2.);
+	assert_eq!(1.5, a.intersection_area(&Rect2d::new(1., 1., 5., 5.)));
+	assert_eq!(0., a.intersection_area(&Rect2d::new(3., 0., 1., 1.)));
+
+	let huge = Rect::new(0, 0, 50000, 50000);
+	assert_eq!(2_500_000_000, huge.intersection_area_i64(&huge));
+	assert_eq!(
+		1_250_000_000,
+		huge.intersection_area_i64(&Rect::new(25000, -10, 50000, 60000))
+	);
+}
+
+#[test]
+fn rect_serde() {
+	#![cfg(feature = "serde")]
+	let rect = Rect::new(1, -2, 3, 4);
+	let json = serde_json::to_string(&rect).unwrap();
+	assert_eq!(r#"{"x":1,"y":-2,"width":3,"height":4}"#, json);
+	assert_eq!(rect, serde_json::from_str::<Rect>(&json).unwrap());
+
+	let rect = Rect2d::new(1.5, -2., 3.25, 4.);
+	let json = serde_json::to_string(&rect).unwrap();
+	assert_eq!(r#"{"x":1.5,"y":-2.0,"width":3.25,"height":4.0}"#, json);
+	assert_eq!(rect, serde_json::from_str::<Rect2d>(&json).unwrap());
+}
+
+#[test]
+fn rect_hash() {
+	let rects: HashSet<Rect> = [
+		Rect::new(1, 2, 3, 4),
+		Rect::new(0, 0, 10, 10),
+		Rect::new(1, 2, 3, 4),
+		Rect::new(1, 2, 4, 3),
+		Rect::new(0, 0, 10, 10),
+	]
+	.into_iter()
+	.collect();
+	assert_eq!(3, rects.len());
+	assert!(rects.contains(&Rect::new(1, 2, 4, 3)));
+
+	let points: HashSet<Point2i> = [Point2i::new(1, 2), Point2i::new(1, 2), Point2i::new(2, 1)]
+		.into_iter()
+		.collect();
+	assert_eq!(2, points.len());
+	let sizes: HashSet<Size2i> = [Size2i::new(1, 2), Size2i::new(1, 2)].into_iter().collect();
+	assert_eq!(1, sizes.len());
+}
+
+#[test]
+fn rect_from_corners() {
+	let rect = Rect::from_corners(Point2i::new(1, 2), Point2i::new(4, 6));
+	assert_eq!(Rect::new(1, 2, 3, 4), rect);
+	assert_eq!(Rect::from_points(rect.tl(), rect.br()), rect);
+	assert_eq!(rect, Rect::from_corners(rect.tl(), rect.br()));
+	assert_eq!(
+		Rect2d::new(-1.5, 0., 3., 0.),
+		Rect2d::from_corners(Point2d::new(-1.5, 0.), Point2d::new(1.5, 0.))
+	);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "br must not lie to the left of or above tl")]
+fn rect_from_corners_reversed() {
+	Rect::from_corners(Point2i::new(4, 6), Point2i::new(1, 2));
+}
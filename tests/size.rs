@@ -1,4 +1,4 @@
-use opencv::core::{Point, Point2d, Size, Size2d, Size2f, Size2i};
+use opencv::core::{GeometryExtent, Point, Point2d, Rect, Rect2d, Size, Size2d, Size2f, Size2i};
 
 #[test]
 fn size_add() {
@@ -34,6 +34,18 @@ fn size_sub() {
 	}
 }
 
+#[test]
+fn size_componentwise() {
+	let a = Size2d::new(1.5, -2.);
+	let b = Size2d::new(-0.5, 10.);
+	assert_eq!(a + b, Size2d::new(1., 8.));
+	assert_eq!(a - b, Size2d::new(2., -12.));
+	let mut out = a;
+	out += b;
+	out -= a;
+	assert_eq!(out, b);
+}
+
 #[test]
 fn size_mul() {
 	let src = Size2f::new(50., 50.);
@@ -93,3 +105,138 @@ fn size_from() {
 	assert_eq!(Size2f::new(1., 2.), (1., 2.).into());
 	assert_eq!(Size::new(1, 2), Point::new(1, 2).into());
 }
+
+#[test]
+fn size_tuple_array_conv() {
+	let size = Size::new(640, 480);
+	assert_eq!(size, Size::from((640, 480)));
+	assert_eq!(size, Size::from([640, 480]));
+	assert_eq!((640, 480), <(i32, i32)>::from(size));
+	assert_eq!([640, 480], <[i32; 2]>::from(size));
+	let tuple: (f32, f32) = Size2f::new(1.5, 2.5).into();
+	assert_eq!(Size2f::new(1.5, 2.5), tuple.into());
+	let arr: [f32; 2] = Size2f::new(1.5, 2.5).into();
+	assert_eq!(Size2f::new(1.5, 2.5), arr.into());
+}
+
+#[test]
+fn size_at_origin() {
+	assert_eq!(Rect::new(0, 0, 10, 20), Size::new(10, 20).at_origin());
+	assert_eq!(Rect2d::new(0., 0., 1.5, 2.5), Size2d::new(1.5, 2.5).at_origin());
+	assert_eq!(Rect::new(0, 0, 0, 0), Size::default().at_origin());
+}
+
+#[test]
+fn size_aspect_ratio_reduced() {
+	assert_eq!((16, 9), Size::new(1920, 1080).aspect_ratio_reduced());
+	assert_eq!((1, 1), Size::new(100, 100).aspect_ratio_reduced());
+	assert_eq!((4, 3), Size::new(640, 480).aspect_ratio_reduced());
+	assert_eq!((7, 13), Size::new(7, 13).aspect_ratio_reduced());
+	assert_eq!((-2, 1), Size::new(-20, 10).aspect_ratio_reduced());
+	assert_eq!((1, 0), Size::new(5, 0).aspect_ratio_reduced());
+	assert_eq!((0, 1), Size::new(0, 5).aspect_ratio_reduced());
+	assert_eq!((0, 0), Size::new(0, 0).aspect_ratio_reduced());
+	assert_eq!((-1, -1), Size::new(i32::MIN, i32::MIN).aspect_ratio_reduced());
+	assert_eq!((-1, 0), Size::new(i32::MIN, 0).aspect_ratio_reduced());
+}
+
+#[test]
+fn size_aspect_ratio() {
+	assert_eq!(16. / 9., Size::new(1920, 1080).aspect_ratio());
+	assert_eq!(0.5, Size2f::new(1.5, 3.).aspect_ratio());
+	assert_eq!(f64::INFINITY, Size::new(10, 0).aspect_ratio());
+	assert!(Size::new(0, 0).aspect_ratio().is_nan());
+}
+
+#[test]
+fn size_fit_within() {
+	// landscape into a square
+	assert_eq!(Size::new(256, 144), Size::new(1920, 1080).fit_within(Size::new(256, 256)));
+	assert_eq!(
+		Size2d::new(256., 144.),
+		Size2d::new(1920., 1080.).fit_within(Size2d::new(256., 256.))
+	);
+	// portrait into a landscape
+	assert_eq!(Size::new(270, 480), Size::new(1080, 1920).fit_within(Size::new(640, 480)));
+	assert_eq!(
+		Size2f::new(270., 480.),
+		Size2f::new(1080., 1920.).fit_within(Size2f::new(640., 480.))
+	);
+	// rounded down
+	assert_eq!(Size::new(100, 33), Size::new(300, 100).fit_within(Size::new(100, 100)));
+	// no upscaling
+	assert_eq!(Size::new(100, 50), Size::new(100, 50).fit_within(Size::new(640, 480)));
+	assert_eq!(Size::new(0, 0), Size::new(0, 0).fit_within(Size::new(640, 480)));
+	// degenerate
+	assert_eq!(Size::new(0, 480), Size::new(0, 1000).fit_within(Size::new(640, 480)));
+	assert_eq!(Size::new(0, 0), Size::new(10, 10).fit_within(Size::new(0, 0)));
+	for size_n in 1..200 {
+		let size = Size::new(size_n * 7, 1000 - size_n * 3);
+		let bounds = Size::new(137, 91);
+		let fit = size.fit_within(bounds);
+		assert!(fit.width <= bounds.width && fit.height <= bounds.height, "{size} -> {fit}");
+	}
+}
+
+#[test]
+fn size_checked_area() {
+	assert_eq!(Some(6), Size::new(2, 3).checked_area());
+	assert_eq!(6, Size::new(2, 3).area_i64());
+
+	let huge = Size::new(46341, 46341);
+	assert!(46341i64 * 46341 > i64::from(i32::MAX));
+	assert_eq!(None, huge.checked_area());
+	assert_eq!(2_147_488_281, huge.area_i64());
+	assert_eq!(Some(i32::MAX), Size::new(i32::MAX, 1).checked_area());
+	assert_eq!(None, Size::new(i32::MIN, -1).checked_area());
+	assert_eq!(-2 * i64::from(i32::MAX), Size::new(i32::MAX, -2).area_i64());
+}
+
+#[test]
+fn size_zero() {
+	assert_eq!(Size::new(0, 0), Size::zero());
+	assert_eq!(Size2d::default(), Size2d::zero());
+	assert!(Size2f::zero().empty());
+	assert_eq!(0, Size2i::zero().area());
+}
+
+#[test]
+fn geometry_extent() {
+	fn is_landscape<E: GeometryExtent>(extent: &E) -> bool
+	where
+		E::Scalar: PartialOrd,
+	{
+		extent.width() > extent.height()
+	}
+
+	assert!(is_landscape(&Size::new(640, 480)));
+	assert!(!is_landscape(&Rect::new(10, 20, 30, 40)));
+	assert!(is_landscape(&Rect2d::new(0., 0., 1.5, 1.)));
+
+	let size = Size2f::new(3., 4.);
+	assert_eq!(3., GeometryExtent::width(&size));
+	assert_eq!(4., GeometryExtent::height(&size));
+	let rect = Rect::new(1, 2, 3, 4);
+	assert_eq!(rect.size(), Size::new(rect.width(), rect.height()));
+}
+
+#[test]
+fn size_serde() {
+	#![cfg(feature = "serde")]
+	let sz = Size::new(3, 4);
+	let json = serde_json::to_string(&sz).unwrap();
+	assert_eq!(r#"{"width":3,"height":4}"#, json);
+	assert_eq!(sz, serde_json::from_str::<Size>(&json).unwrap());
+
+	let sz = Size2d::new(3.25, 4.);
+	let json = serde_json::to_string(&sz).unwrap();
+	assert_eq!(r#"{"width":3.25,"height":4.0}"#, json);
+	assert_eq!(sz, serde_json::from_str::<Size2d>(&json).unwrap());
+}
+
+#[test]
+fn size_display() {
+	assert_eq!("[3 x 4]", Size::new(3, 4).to_string());
+	assert_eq!("[3.25 x 4]", Size2d::new(3.25, 4.).to_string());
+	assert_eq!("[0.5 x -1]", Size2f::new(0.5, -1.).to_string());
+}
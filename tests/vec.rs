@@ -1,4 +1,4 @@
-use opencv::core::{Vec2d, Vec2i, Vec3b, Vec3d, Vec3i, Vec3s, Vec4f, Vec4s};
+use opencv::core::{Scalar, Vec2d, Vec2i, Vec3b, Vec3d, Vec3i, Vec3s, Vec4f, Vec4s};
 
 #[test]
 fn vec() {
@@ -93,3 +93,23 @@ fn vec_to() {
 		assert_eq!(Some(Vec3i::from([1, 10, 50])), a.to());
 	}
 }
+
+#[test]
+fn scalar_ops() {
+	let a = Scalar::new(10., 20., 30., 255.);
+	let b = Scalar::new(1.5, -2., 0., 5.);
+	assert_eq!(Scalar::new(11.5, 18., 30., 260.), a + b);
+	assert_eq!(Scalar::new(8.5, 22., 30., 250.), a - b);
+	assert_eq!(Scalar::new(20., 40., 60., 510.), a * 2.);
+	assert_eq!(Scalar::new(2.5, 5., 7.5, 63.75), a / 4.);
+	assert_eq!(Scalar::new(-1.5, 2., 0., -5.), -b);
+
+	let mut c = Scalar::all(1.);
+	assert_eq!(Scalar::new(1., 1., 1., 1.), c);
+	c += a;
+	c -= Scalar::all(0.5);
+	c *= 2.;
+	assert_eq!(Scalar::new(21., 41., 61., 511.), c);
+	c /= 4.;
+	assert_eq!(Scalar::new(5.25, 10.25, 15.25, 127.75), c);
+}
@@ -7,7 +7,8 @@ use opencv::core::{DMatch, Point2d, Point2f, Scalar, SparseMat_Hdr, Vec4i};
 use opencv::prelude::*;
 use opencv::types::{
 	VectorOfDMatch, VectorOfKeyPoint, VectorOfMat, VectorOfPoint2d, VectorOfPoint2f, VectorOfRange, VectorOfString, VectorOfVec4i,
-	VectorOfVectorOfPoint2f, VectorOfbool, VectorOfc_char, VectorOff64, VectorOfi32, VectorOfi8, VectorOfu8,
+	VectorOfVectorOfDMatch, VectorOfVectorOfPoint2f, VectorOfbool, VectorOfc_char, VectorOff64, VectorOfi32, VectorOfi8,
+	VectorOfu8,
 };
 use opencv::{core, Error, Result};
 
@@ -242,6 +243,58 @@ fn vector_of_vector_simple_struct() -> Result<()> {
 	Ok(())
 }
 
+#[test]
+fn flatten() -> Result<()> {
+	let mut outer = VectorOfVectorOfDMatch::new();
+	outer.push(VectorOfDMatch::from_iter([
+		DMatch::new_index(0, 1, 10, 12.4)?,
+		DMatch::new_index(0, 2, 10, 15.1)?,
+	]));
+	outer.push(VectorOfDMatch::from_iter([
+		DMatch::new_index(1, 3, 10, 7.2)?,
+		DMatch::new_index(1, 4, 10, 9.8)?,
+	]));
+	let flat = outer.flatten()?;
+	assert_eq!(4, flat.len());
+	assert_eq!(
+		vec![(0, 1), (0, 2), (1, 3), (1, 4)],
+		flat.iter().map(|m| (m.query_idx, m.train_idx)).collect::<Vec<_>>()
+	);
+	assert_eq!(9.8, flat.get(3)?.distance);
+
+	assert!(VectorOfVectorOfDMatch::new().flatten()?.is_empty());
+	Ok(())
+}
+
+#[test]
+fn append_concat() -> Result<()> {
+	let first = VectorOfDMatch::from_iter([DMatch::new_index(0, 1, 10, 12.4)?, DMatch::new_index(0, 2, 10, 15.1)?]);
+	let second = VectorOfDMatch::from_iter([DMatch::new_index(1, 3, 10, 7.2)?]);
+
+	let mut appended = first.clone();
+	appended.append(&second)?;
+	assert_eq!(3, appended.len());
+	assert_eq!(2, first.len());
+	assert_eq!(1, second.len());
+	assert_eq!(
+		vec![(0, 1), (0, 2), (1, 3)],
+		appended.iter().map(|m| (m.query_idx, m.train_idx)).collect::<Vec<_>>()
+	);
+	appended.append(&VectorOfDMatch::new())?;
+	assert_eq!(3, appended.len());
+
+	let concatenated = second.clone().concat(&first);
+	assert_eq!(
+		vec![(1, 3), (0, 1), (0, 2)],
+		concatenated.iter().map(|m| (m.query_idx, m.train_idx)).collect::<Vec<_>>()
+	);
+
+	let mut strings = VectorOfString::from_iter(["a", "b"]);
+	strings.append(&VectorOfString::from_iter(["c"]))?;
+	assert_eq!(vec!["a", "b", "c"], strings.to_vec());
+	Ok(())
+}
+
 #[test]
 fn from_elem() {
 	let v = VectorOfi32::from_elem(5, 10);
@@ -604,6 +657,17 @@ fn as_slice() -> Result<()> {
 			vec.as_slice()
 		);
 	}
+	{
+		let src = [Point2f::new(1.5, -2.), Point2f::new(3., 4.25), Point2f::new(0., 100.)];
+		let vec = VectorOfPoint2f::from_iter(src);
+		let slice = vec.as_slice();
+		assert_eq!(3, slice.len());
+		assert_eq!(&src, slice);
+		// zero-copy: the slice points into the storage of the vector
+		assert_eq!(slice.as_ptr(), vec.as_slice().as_ptr());
+		assert_eq!(vec.to_vec(), slice);
+		assert!(VectorOfPoint2f::new().as_slice().is_empty());
+	}
 	{
 		let default = DMatch::default()?;
 		let src = vec![DMatch::default()?, DMatch::new(1, 2, 9.)?];
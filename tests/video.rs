@@ -0,0 +1,73 @@
+#![cfg(ocvrs_has_module_video)]
+
+use opencv::core::{Point, Point2f, Rect, Scalar, Vector};
+use opencv::prelude::*;
+use opencv::{core, video, Result};
+
+fn frame(shift_x: f64) -> Result<Mat> {
+	let mut out = Mat::new_rows_cols_with_default(64, 64, u8::opencv_type(), Scalar::all(0.))?;
+	let cols = out.cols() as usize;
+	for (i, v) in out.data_bytes_mut()?.iter_mut().enumerate() {
+		let x = (i % cols) as f64 - shift_x;
+		let y = (i / cols) as f64;
+		*v = (128. + 100. * (x / 5.).sin() * (y / 5.).cos()) as u8;
+	}
+	Ok(out)
+}
+
+#[test]
+fn optical_flow_farneback() -> Result<()> {
+	let prev = frame(0.)?;
+	let next = frame(2.)?;
+	let flow = prev.optical_flow_farneback(&next)?;
+	assert_eq!(prev.size()?, flow.size()?);
+	assert_eq!(2, flow.channels());
+
+	let center = video::flow_at(&flow, Point::new(32, 32))?;
+	assert!(center.x > 1. && center.x < 3., "Unexpected flow: {center:?}");
+	assert!(center.y.abs() < 0.5, "Unexpected flow: {center:?}");
+
+	let still = prev.optical_flow_farneback(&prev)?;
+	assert!(video::flow_at(&still, Point::new(32, 32))?.norm() < 0.1);
+	assert!(video::flow_at(&still, Point::new(64, 32)).is_err());
+	Ok(())
+}
+
+#[test]
+fn optical_flow_pyr_lk() -> Result<()> {
+	let prev = frame(0.)?;
+	let next = frame(2.)?;
+	let prev_pts = Vector::<Point2f>::from_iter([Point2f::new(32., 32.), Point2f::new(24., 40.)]);
+	let res = prev.optical_flow_pyr_lk(&next, &prev_pts)?;
+	assert_eq!(prev_pts.len(), res.next_points.len());
+	assert_eq!(prev_pts.len(), res.status.len());
+	assert_eq!(prev_pts.len(), res.errors.len());
+	for ((prev_pt, next_pt), status) in prev_pts.iter().zip(res.next_points.iter()).zip(res.status) {
+		assert!(status);
+		let shift = next_pt - prev_pt;
+		assert!(shift.x > 1. && shift.x < 3., "Unexpected shift: {shift:?}");
+		assert!(shift.y.abs() < 0.5, "Unexpected shift: {shift:?}");
+	}
+	Ok(())
+}
+
+#[test]
+fn simple_background_subtractor() -> Result<()> {
+	let mut subtractor = video::SimpleBackgroundSubtractor::new(10, 16.)?;
+	let background = frame(0.)?;
+	for _ in 0..5 {
+		subtractor.apply(&background)?;
+	}
+	let still = subtractor.apply(&background)?;
+	assert_eq!(background.size()?, still.size()?);
+	assert_eq!(u8::opencv_type(), still.typ());
+	assert_eq!(0, core::count_non_zero(&still)?);
+
+	let mut moved = background.try_clone()?;
+	moved.roi_mut(Rect::new(20, 20, 16, 16))?.set_to_def(&Scalar::all(255.))?;
+	let mask = subtractor.apply(&moved)?;
+	assert!(core::count_non_zero(&mask)? > 0);
+	assert_eq!(255, *mask.at_2d::<u8>(28, 28)?);
+	assert_eq!(0, *mask.at_2d::<u8>(5, 5)?);
+	Ok(())
+}
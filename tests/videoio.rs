@@ -2,7 +2,10 @@
 
 use matches::assert_matches;
 
-use opencv::{core, videoio::VideoWriter, Error, Result};
+use opencv::core::{Mat, Scalar, Size};
+use opencv::prelude::*;
+use opencv::videoio::{VideoCapture, VideoWriter, CAP_ANY};
+use opencv::{core, Error, Result};
 
 #[test]
 fn fourcc() -> Result<()> {
@@ -18,3 +21,31 @@ fn fourcc() -> Result<()> {
 	);
 	Ok(())
 }
+
+#[test]
+fn video_capture_frames() -> Result<()> {
+	let path = std::env::temp_dir().join(format!("ocvrs_frames_{}.avi", std::process::id()));
+	let path = path.to_str().expect("Non-UTF-8 temp dir");
+	let size = Size::new(64, 48);
+	{
+		let mut writer = VideoWriter::new(path, VideoWriter::fourcc('M', 'J', 'P', 'G')?, 10., size, true)?;
+		assert!(writer.is_opened()?);
+		for i in 0..5 {
+			let frame = Mat::new_size_with_default(size, core::CV_8UC3, Scalar::all(f64::from(i * 40)))?;
+			writer.write(&frame)?;
+		}
+		writer.release()?;
+	}
+
+	let mut capture = VideoCapture::from_file(path, CAP_ANY)?;
+	assert!(capture.is_opened()?);
+	let frames = capture.frames().collect::<Result<Vec<_>>>()?;
+	assert_eq!(5, frames.len());
+	for frame in &frames {
+		assert_eq!(size, frame.size()?);
+	}
+	// the stream is exhausted
+	assert_eq!(0, capture.frames().count());
+	std::fs::remove_file(path).ok();
+	Ok(())
+}